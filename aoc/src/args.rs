@@ -0,0 +1,294 @@
+use common::{generate::Size, Part};
+
+pub const USAGE: &str = "Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path|->] [--format <table|json>] [--jobs <n>]
+    aoc bench <day|all> [--part <1|2>] [--input <path|->] [--iterations <n>]
+    aoc verify [day|all] [--part <1|2>]
+    aoc record <day> [--part <1|2>] [--answer <answer>]
+    aoc fetch <day> [--force]
+    aoc new <day>
+    aoc gen <day> --size <n|nxm> [--seed <n>]
+
+fetch reads the session cookie from AOC_SESSION, and optionally the site from
+AOC_BASE_URL and the download cache directory from AOC_CACHE_DIR.";
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        selection: Selection,
+        format: Format,
+        jobs: usize,
+    },
+    Bench {
+        selection: Selection,
+        iterations: usize,
+    },
+    Verify(Selection),
+    Record {
+        selection: Selection,
+        answer: Option<String>,
+    },
+    Fetch {
+        day: u8,
+        force: bool,
+    },
+    New(u8),
+    Gen {
+        day: u8,
+        size: Size,
+        seed: u64,
+    },
+    Help,
+}
+
+/// Which days and parts to solve, and with which input.
+#[derive(Debug, PartialEq)]
+pub struct Selection {
+    pub days: Days,
+    pub part: Option<Part>,
+    pub input: Option<String>,
+}
+
+impl Selection {
+    pub fn parts(&self) -> Vec<Part> {
+        Part::ALL
+            .into_iter()
+            .filter(|p| self.part.is_none_or(|part| part == *p))
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Days {
+    All,
+    One(u8),
+}
+
+/// Everything that was passed after the subcommand. Each command accepts the
+/// options it understands and rejects the rest.
+#[derive(Default)]
+struct Flags {
+    days: Option<Days>,
+    part: Option<Part>,
+    input: Option<String>,
+    answer: Option<String>,
+    iterations: Option<usize>,
+    format: Option<Format>,
+    jobs: Option<usize>,
+    size: Option<Size>,
+    seed: Option<u64>,
+    force: bool,
+}
+
+impl Flags {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut flags = Self::default();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or(format!("Missing value for {}", name));
+
+            match arg.as_str() {
+                "-p" | "--part" => flags.part = Some(parse_part(&value("--part")?)?),
+                "-i" | "--input" => flags.input = Some(value("--input")?),
+                "-a" | "--answer" => flags.answer = Some(value("--answer")?),
+                "-f" | "--format" => flags.format = Some(parse_format(&value("--format")?)?),
+                "-j" | "--jobs" => flags.jobs = Some(parse_jobs(&value("--jobs")?)?),
+                "-s" | "--size" => flags.size = Some(value("--size")?.parse()?),
+                "--seed" => flags.seed = Some(parse_seed(&value("--seed")?)?),
+                "--force" => flags.force = true,
+                "-n" | "--iterations" => {
+                    flags.iterations = Some(parse_iterations(&value("--iterations")?)?)
+                }
+                _ if flags.days.is_none() && !arg.starts_with('-') => {
+                    flags.days = Some(parse_days(&arg)?)
+                }
+                _ => return Err(format!("Unexpected argument: {}", arg)),
+            }
+        }
+
+        Ok(flags)
+    }
+
+    /// Fails if an option outside of `allowed` was given. `--part` is
+    /// accepted by every command but `fetch`, `new` and `gen`, which check
+    /// it themselves.
+    fn allow(&self, command: &str, allowed: &[&str]) -> Result<(), String> {
+        let given = [
+            ("--input", self.input.is_some()),
+            ("--answer", self.answer.is_some()),
+            ("--iterations", self.iterations.is_some()),
+            ("--format", self.format.is_some()),
+            ("--jobs", self.jobs.is_some()),
+            ("--size", self.size.is_some()),
+            ("--seed", self.seed.is_some()),
+            ("--force", self.force),
+        ];
+
+        match given
+            .iter()
+            .find(|(flag, given)| *given && !allowed.contains(flag))
+        {
+            Some((flag, _)) => Err(format!("{} cannot be used with {}", flag, command)),
+            None => Ok(()),
+        }
+    }
+
+    fn selection(&mut self, default_days: Option<Days>) -> Result<Selection, String> {
+        let days = self
+            .days
+            .take()
+            .or(default_days)
+            .ok_or("Missing day, expected a number or \"all\"")?;
+        if days == Days::All && self.input.is_some() {
+            return Err("--input can only be used with a single day".to_string());
+        }
+
+        Ok(Selection {
+            days,
+            part: self.part,
+            input: self.input.take(),
+        })
+    }
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Ok(Command::Help),
+    };
+    let mut flags = Flags::parse(args)?;
+
+    match command.as_str() {
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        "run" => {
+            flags.allow("run", &["--input", "--format", "--jobs"])?;
+            Ok(Command::Run {
+                selection: flags.selection(None)?,
+                format: flags.format.unwrap_or_default(),
+                jobs: flags.jobs.unwrap_or(1),
+            })
+        }
+        "bench" => {
+            flags.allow("bench", &["--input", "--iterations"])?;
+            Ok(Command::Bench {
+                selection: flags.selection(None)?,
+                iterations: flags.iterations.unwrap_or(DEFAULT_ITERATIONS),
+            })
+        }
+        "verify" => {
+            flags.allow("verify", &[])?;
+            Ok(Command::Verify(flags.selection(Some(Days::All))?))
+        }
+        "record" => {
+            flags.allow("record", &["--answer"])?;
+            let selection = flags.selection(None)?;
+            if selection.days == Days::All {
+                return Err("record needs a single day".to_string());
+            }
+            if flags.answer.is_some() && selection.part.is_none() {
+                return Err("--answer needs --part".to_string());
+            }
+            Ok(Command::Record {
+                selection,
+                answer: flags.answer,
+            })
+        }
+        "fetch" => {
+            flags.allow("fetch", &["--force"])?;
+            if flags.part.is_some() {
+                return Err("--part cannot be used with fetch".to_string());
+            }
+            match flags.selection(None)?.days {
+                Days::One(day) => Ok(Command::Fetch {
+                    day,
+                    force: flags.force,
+                }),
+                Days::All => Err("fetch needs a single day".to_string()),
+            }
+        }
+        "new" => {
+            flags.allow("new", &[])?;
+            if flags.part.is_some() {
+                return Err("--part cannot be used with new".to_string());
+            }
+            match flags.selection(None)?.days {
+                Days::One(day) => Ok(Command::New(day)),
+                Days::All => Err("new needs a single day".to_string()),
+            }
+        }
+        "gen" => {
+            flags.allow("gen", &["--size", "--seed"])?;
+            if flags.part.is_some() {
+                return Err("--part cannot be used with gen".to_string());
+            }
+            let size = flags.size.ok_or("gen needs --size")?;
+            match flags.selection(None)?.days {
+                Days::One(day) => Ok(Command::Gen {
+                    day,
+                    size,
+                    seed: flags.seed.unwrap_or_default(),
+                }),
+                Days::All => Err("gen needs a single day".to_string()),
+            }
+        }
+        command => Err(format!("Unknown command: {}", command)),
+    }
+}
+
+fn parse_days(value: &str) -> Result<Days, String> {
+    if value == "all" {
+        return Ok(Days::All);
+    }
+
+    match value.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(Days::One(day)),
+        _ => Err(format!("Invalid day: {}", value)),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part: {}", value)),
+    }
+}
+
+fn parse_iterations(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(iterations) if iterations > 0 => Ok(iterations),
+        _ => Err(format!("Invalid iteration count: {}", value)),
+    }
+}
+
+fn parse_jobs(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(jobs) if jobs > 0 => Ok(jobs),
+        _ => Err(format!("Invalid job count: {}", value)),
+    }
+}
+
+fn parse_seed(value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid seed: {}", value))
+}
+
+fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "table" => Ok(Format::Table),
+        "json" => Ok(Format::Json),
+        _ => Err(format!("Invalid format: {}", value)),
+    }
+}
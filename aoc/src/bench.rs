@@ -0,0 +1,129 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use common::{DynSolution, ParseError, Part};
+
+/// Every sample of one phase, sorted from fastest to slowest.
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Self { samples }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let middle = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[middle - 1] + self.samples[middle]) / 2
+        } else {
+            self.samples[middle]
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Timings,
+    pub parts: Vec<(Part, Timings)>,
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+pub fn bench_day(
+    solution: &dyn DynSolution,
+    input: &[u8],
+    parts: &[Part],
+    iterations: usize,
+) -> Result<DayBench, ParseError> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part_samples = vec![Vec::with_capacity(iterations); parts.len()];
+
+    for _ in 0..iterations {
+        let (parsed, elapsed) = time(|| solution.parse(black_box(input)));
+        let parsed = parsed?;
+        parse.push(elapsed);
+
+        for (part, samples) in parts.iter().zip(part_samples.iter_mut()) {
+            let (_, elapsed) = time(|| match part {
+                Part::One => solution.part1(parsed.as_ref()),
+                Part::Two => solution.part2(parsed.as_ref()),
+            });
+            samples.push(elapsed);
+        }
+    }
+
+    Ok(DayBench {
+        day: solution.day(),
+        parse: Timings::new(parse),
+        parts: parts
+            .iter()
+            .copied()
+            .zip(part_samples.into_iter().map(Timings::new))
+            .collect(),
+    })
+}
+
+pub fn print_bench(benches: &[DayBench], iterations: usize) {
+    println!("{} iterations", iterations);
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+        "Day", "Phase", "Min", "Median", "Max"
+    );
+
+    for bench in benches {
+        let phases = [("parse".to_string(), &bench.parse)].into_iter().chain(
+            bench
+                .parts
+                .iter()
+                .map(|(part, timings)| (format!("part{}", part), timings)),
+        );
+
+        for (phase, timings) in phases {
+            println!(
+                "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+                bench.day,
+                phase,
+                format!("{:?}", timings.min()),
+                format!("{:?}", timings.median()),
+                format!("{:?}", timings.max())
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(micros: &[u64]) -> Timings {
+        Timings::new(micros.iter().map(|&m| Duration::from_micros(m)).collect())
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let odd = timings(&[5, 1, 3]);
+        assert_eq!(odd.min(), Duration::from_micros(1));
+        assert_eq!(odd.median(), Duration::from_micros(3));
+        assert_eq!(odd.max(), Duration::from_micros(5));
+
+        let even = timings(&[4, 1, 2, 8]);
+        assert_eq!(even.median(), Duration::from_micros(3));
+    }
+}
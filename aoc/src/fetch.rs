@@ -0,0 +1,215 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use common::{project_dir, InputHash};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2022";
+
+/// Downloads puzzle inputs from `<base_url>/day/<day>/input`, keeping a copy of
+/// every download in `cache_dir` so an input is only ever downloaded once.
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: String,
+    pub cache_dir: PathBuf,
+}
+
+/// Where a fetched input came from.
+#[derive(Debug, PartialEq)]
+pub enum Origin {
+    Cache,
+    Download,
+}
+
+impl Fetcher {
+    /// Configures a fetcher from `AOC_SESSION`, and optionally `AOC_BASE_URL`
+    /// and `AOC_CACHE_DIR`. The cache defaults to `.aoc-cache` in the
+    /// workspace.
+    pub fn from_env() -> Result<Self, String> {
+        let session = env::var("AOC_SESSION")
+            .map_err(|_| "AOC_SESSION must be set to the session cookie of your account")?;
+
+        Ok(Self {
+            base_url: env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session,
+            cache_dir: env::var_os("AOC_CACHE_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| project_dir(".aoc-cache")),
+        })
+    }
+
+    /// The cached input of `day`. Inputs differ between accounts, so the cache
+    /// is split by a fingerprint of the base URL and session.
+    fn cache_path(&self, day: u8) -> PathBuf {
+        let account = InputHash::of(format!("{}\n{}", self.base_url, self.session).as_bytes());
+        self.cache_dir
+            .join(account.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// The input of `day`, from the cache if it was downloaded before.
+    pub fn input(&self, day: u8) -> Result<(String, Origin), String> {
+        let cache_path = self.cache_path(day);
+        match fs::read_to_string(&cache_path) {
+            Ok(input) => return Ok((input, Origin::Cache)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(format!("{}: {}", cache_path.display(), error)),
+        }
+
+        let input = self.download(day)?;
+
+        let write = |path: &Path| {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(path, &input)
+        };
+        write(&cache_path).map_err(|error| format!("{}: {}", cache_path.display(), error))?;
+
+        Ok((input, Origin::Download))
+    }
+
+    fn download(&self, day: u8) -> Result<String, String> {
+        let url = format!("{}/day/{}/input", self.base_url.trim_end_matches('/'), day);
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        let response = agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "aoc input fetcher")
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(status, _) => format!("{}: server answered {}", url, status),
+                ureq::Error::Transport(error) => format!("{}: {}", url, error),
+            })?;
+
+        response
+            .into_string()
+            .map_err(|error| format!("{}: {}", url, error))
+    }
+}
+
+/// Writes the input of `day` to `path`. An existing file is only replaced
+/// when `force` is set.
+pub fn fetch_to(fetcher: &Fetcher, day: u8, path: &Path, force: bool) -> Result<Origin, String> {
+    if !force && path.exists() {
+        return Err(format!(
+            "{} already exists, use --force to replace it",
+            path.display()
+        ));
+    }
+
+    let (input, origin) = fetcher.input(day)?;
+    fs::write(path, input).map_err(|error| format!("{}: {}", path.display(), error))?;
+
+    Ok(origin)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::*;
+
+    /// Serves `/day/<n>/input` on a local port, answering only requests that
+    /// carry the `secret` session, and counts the requests it receives.
+    fn stand_in_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let mut request = Vec::new();
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    request.push(line);
+                }
+
+                let path = request[0].split(' ').nth(1).unwrap_or_default().to_string();
+                let authorised = request
+                    .iter()
+                    .any(|line| line.eq_ignore_ascii_case("cookie: session=secret"));
+
+                let (status, body) = match path.strip_prefix("/day/") {
+                    Some(rest) if authorised => ("200 OK", format!("input of {}\n", rest)),
+                    Some(_) => ("400 Bad Request", String::new()),
+                    None => ("404 Not Found", String::new()),
+                };
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn downloads_once_and_then_uses_the_cache() {
+        let (base_url, requests) = stand_in_server();
+        let dir = scratch_dir("cache");
+        let fetcher = Fetcher {
+            base_url,
+            session: "secret".to_string(),
+            cache_dir: dir.join("cache"),
+        };
+        let path = dir.join("input.txt");
+
+        assert_eq!(fetch_to(&fetcher, 3, &path, false), Ok(Origin::Download));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input of 3/input\n");
+
+        assert!(fetch_to(&fetcher, 3, &path, false).is_err());
+        assert_eq!(fetch_to(&fetcher, 3, &path, true), Ok(Origin::Cache));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_rejected_sessions() {
+        let (base_url, _) = stand_in_server();
+        let dir = scratch_dir("session");
+        let fetcher = Fetcher {
+            base_url,
+            session: "expired".to_string(),
+            cache_dir: dir.join("cache"),
+        };
+
+        let error = fetcher.input(1).unwrap_err();
+        assert!(error.ends_with("server answered 400"), "{}", error);
+        assert!(!fetcher.cache_path(1).exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+use serde::Serialize;
+
+use crate::{runner::DayReport, Failure};
+
+#[derive(Serialize)]
+struct PartEntry<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ns: u64,
+    parse_ns: u64,
+    input_hash: String,
+}
+
+#[derive(Serialize)]
+struct ErrorEntry {
+    day: u8,
+    error: String,
+}
+
+/// One JSON object per solved part and one with an `error` field per failed
+/// day, in an array ordered by day.
+pub fn to_json(reports: &[DayReport], failures: &[(u8, Failure)]) -> String {
+    let parts = reports.iter().flat_map(|report| {
+        report.parts.iter().map(move |part| {
+            let entry = PartEntry {
+                day: report.day,
+                part: part.part.number(),
+                answer: &part.answer,
+                elapsed_ns: part.elapsed.as_nanos() as u64,
+                parse_ns: report.parse.as_nanos() as u64,
+                input_hash: report.input_hash.to_string(),
+            };
+            (report.day, serde_json::to_string(&entry))
+        })
+    });
+    let errors = failures.iter().map(|(day, failure)| {
+        let entry = ErrorEntry {
+            day: *day,
+            error: failure.to_string(),
+        };
+        (*day, serde_json::to_string(&entry))
+    });
+
+    let mut entries = parts.chain(errors).collect::<Vec<_>>();
+    entries.sort_by_key(|(day, _)| *day);
+
+    let entries = entries
+        .into_iter()
+        .map(|(_, entry)| format!("  {}", entry.expect("entries serialise to JSON")))
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", entries.join(",\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::{InputHash, ParseError, Part};
+
+    use super::*;
+    use crate::runner::PartResult;
+
+    #[test]
+    fn reports_answers_and_failed_days() {
+        let reports = [DayReport {
+            day: 3,
+            input_hash: InputHash::of(b"input"),
+            parse: Duration::from_nanos(5),
+            parts: vec![PartResult {
+                part: Part::Two,
+                answer: "#.\n\"#\"".to_string(),
+                elapsed: Duration::from_nanos(7),
+            }],
+        }];
+        let failures = [
+            (1, Failure::Panic("oops".to_string())),
+            (4, Failure::Parse(ParseError::new(1, "a number", Some("x")))),
+        ];
+
+        let json = to_json(&reports, &failures);
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0]["day"], 1);
+        assert_eq!(entries[0]["error"], "panicked: oops");
+        assert_eq!(entries[1]["day"], 3);
+        assert_eq!(entries[1]["part"], 2);
+        assert_eq!(entries[1]["answer"], "#.\n\"#\"");
+        assert_eq!(entries[1]["elapsed_ns"], 7);
+        assert_eq!(entries[1]["parse_ns"], 5);
+        assert_eq!(
+            entries[1]["input_hash"],
+            InputHash::of(b"input").to_string()
+        );
+        assert_eq!(entries[2]["day"], 4);
+        assert!(entries[2]["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("invalid input: "));
+    }
+
+    #[test]
+    fn prints_an_empty_array() {
+        assert_eq!(to_json(&[], &[]), "[]");
+    }
+}
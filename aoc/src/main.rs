@@ -0,0 +1,418 @@
+use std::{
+    fmt,
+    io::{ErrorKind, Write},
+    panic::{self, AssertUnwindSafe},
+    process::ExitCode,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Instant,
+};
+
+use args::{parse_args, Command, Days, Format, Selection, USAGE};
+use bench::{bench_day, print_bench};
+use common::{
+    generate::{Rng, Size},
+    project_dir, Answers, DynSolution, InputSource, InputWarning, ParseError, Registry,
+};
+use fetch::{fetch_to, Fetcher, Origin};
+use runner::{print_table, run_day, DayReport};
+use verify::print_verification;
+
+mod args;
+mod bench;
+mod fetch;
+mod json;
+mod runner;
+mod scaffold;
+mod verify;
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    day01::register(&mut registry);
+    day02::register(&mut registry);
+    day03::register(&mut registry);
+    day04::register(&mut registry);
+    day05::register(&mut registry);
+    day06::register(&mut registry);
+    day07::register(&mut registry);
+    day08::register(&mut registry);
+    day09::register(&mut registry);
+    day10::register(&mut registry);
+
+    registry
+}
+
+fn project(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+/// Why a day could not be solved.
+enum Failure {
+    Unsolved,
+    Read(std::io::Error),
+    Parse(ParseError),
+    Panic(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Unsolved => write!(f, "not solved yet"),
+            Failure::Read(error) => write!(f, "failed to read input: {}", error),
+            Failure::Parse(error) => write!(f, "invalid input: {}", error),
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+/// Reads the input of one day and calls `f` with it, turning a panic into a
+/// failure so it cannot take the other days down with it.
+fn run_one<T>(
+    solution: &dyn DynSolution,
+    input: Option<&str>,
+    f: &impl Fn(&dyn DynSolution, &[u8]) -> Result<T, ParseError>,
+) -> (Vec<InputWarning>, Result<T, Failure>) {
+    let project = project(solution.day());
+    let input = match InputSource::from_arg(&project, input).read_bytes() {
+        Ok(input) => input,
+        Err(error) => return (Vec::new(), Err(Failure::Read(error))),
+    };
+
+    let warnings = InputWarning::check(&input, solution.padded_lines());
+    let result = match panic::catch_unwind(AssertUnwindSafe(|| f(solution, &input))) {
+        Ok(result) => result.map_err(Failure::Parse),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(Failure::Panic(message))
+        }
+    };
+
+    (warnings, result)
+}
+
+/// Calls `f` with the input of every selected day on up to `jobs` threads,
+/// reporting days that could not be solved on stderr. Results and failures
+/// come back in day order.
+fn for_each_day<T: Send>(
+    registry: &Registry,
+    selection: &Selection,
+    jobs: usize,
+    f: impl Fn(&dyn DynSolution, &[u8]) -> Result<T, ParseError> + Sync,
+) -> (Vec<T>, Vec<(u8, Failure)>) {
+    let solutions = match selection.days {
+        Days::All => registry.iter().collect::<Vec<_>>(),
+        Days::One(day) => match registry.get(day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("Day {} is not solved yet", day);
+                return (Vec::new(), vec![(day, Failure::Unsolved)]);
+            }
+        },
+    };
+
+    let next = AtomicUsize::new(0);
+    let mut outcomes = thread::scope(|scope| {
+        let workers = (0..jobs.min(solutions.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut outcomes = Vec::new();
+                    while let Some(&solution) = solutions.get(next.fetch_add(1, Ordering::Relaxed))
+                    {
+                        let outcome = run_one(solution, selection.input.as_deref(), &f);
+                        outcomes.push((solution.day(), outcome));
+                    }
+                    outcomes
+                })
+            })
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+    outcomes.sort_by_key(|(day, _)| *day);
+
+    let mut reports = Vec::with_capacity(outcomes.len());
+    let mut failures = Vec::new();
+
+    for (day, (warnings, result)) in outcomes {
+        for warning in warnings {
+            eprintln!("Day {}: warning: {}", day, warning);
+        }
+
+        let failure = match result {
+            Ok(report) => {
+                reports.push(report);
+                continue;
+            }
+            Err(failure) => failure,
+        };
+
+        eprintln!("Day {}: {}", day, failure);
+        if let Failure::Read(error) = &failure {
+            if error.kind() == ErrorKind::NotFound && selection.input.is_none() {
+                eprintln!("Run `aoc fetch {}` to download it", day);
+            }
+        }
+        failures.push((day, failure));
+    }
+
+    (reports, failures)
+}
+
+fn solve(
+    registry: &Registry,
+    selection: &Selection,
+    jobs: usize,
+) -> (Vec<DayReport>, Vec<(u8, Failure)>) {
+    let parts = selection.parts();
+    for_each_day(registry, selection, jobs, |solution, input| {
+        run_day(solution, input, &parts)
+    })
+}
+
+fn record(reports: &[DayReport], answer: Option<String>) -> std::io::Result<()> {
+    for report in reports {
+        let project = project(report.day);
+        let mut answers = Answers::load(&project)?;
+        answers.input = Some(report.input_hash);
+
+        for part in &report.parts {
+            let answer = answer.clone().unwrap_or_else(|| part.answer.clone());
+            println!("Day {} part {}: recorded {}", report.day, part.part, answer);
+            answers.set(part.part, answer);
+        }
+
+        answers.save(&project)?;
+    }
+
+    Ok(())
+}
+
+fn fetch(day: u8, force: bool) -> Result<(), String> {
+    let project = project(day);
+    let dir = project_dir(&project);
+    if !dir.is_dir() {
+        return Err(format!("{} does not exist", dir.display()));
+    }
+
+    let fetcher = Fetcher::from_env()?;
+    let path = dir.join("input.txt");
+    let origin = fetch_to(&fetcher, day, &path, force)?;
+
+    match origin {
+        Origin::Cache => println!("Day {}: wrote {} from the cache", day, path.display()),
+        Origin::Download => println!("Day {}: downloaded {}", day, path.display()),
+    }
+
+    Ok(())
+}
+
+fn generate(registry: &Registry, day: u8, size: Size, seed: u64) -> Result<(), String> {
+    let solution = registry
+        .get(day)
+        .ok_or_else(|| format!("Day {} is not solved yet", day))?;
+    let input = solution
+        .generate(&mut Rng::new(seed), size)
+        .ok_or_else(|| format!("Day {} has no generator", day))?;
+
+    std::io::stdout()
+        .lock()
+        .write_all(input.as_bytes())
+        .map_err(|error| error.to_string())
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let registry = registry();
+
+    let ok = match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            true
+        }
+        Command::Run {
+            selection,
+            format,
+            jobs,
+        } => {
+            let start = Instant::now();
+            let (reports, failures) = solve(&registry, &selection, jobs);
+            let wall_time = start.elapsed();
+            match format {
+                Format::Table => print_table(&reports, wall_time),
+                Format::Json => println!("{}", json::to_json(&reports, &failures)),
+            }
+            failures.is_empty()
+        }
+        Command::Bench {
+            selection,
+            iterations,
+        } => {
+            let parts = selection.parts();
+            let (benches, failures) = for_each_day(&registry, &selection, 1, |solution, input| {
+                bench_day(solution, input, &parts, iterations)
+            });
+            print_bench(&benches, iterations);
+            failures.is_empty()
+        }
+        Command::Verify(selection) => {
+            let (reports, failures) = solve(&registry, &selection, 1);
+            print_verification(&reports) && failures.is_empty()
+        }
+        Command::Fetch { day, force } => match fetch(day, force) {
+            Ok(()) => true,
+            Err(error) => {
+                eprintln!("Failed to fetch input: {}", error);
+                false
+            }
+        },
+        Command::New(day) => match scaffold::new_day(day) {
+            Ok(()) => {
+                println!(
+                    "Created day{:02}, put the example from the puzzle in day{:02}/example.txt",
+                    day, day
+                );
+                true
+            }
+            Err(error) => {
+                eprintln!("Failed to create day {}: {}", day, error);
+                false
+            }
+        },
+        Command::Gen { day, size, seed } => match generate(&registry, day, size, seed) {
+            Ok(()) => true,
+            Err(error) => {
+                eprintln!("Failed to generate input: {}", error);
+                false
+            }
+        },
+        Command::Record { selection, answer } => {
+            let (reports, failures) = solve(&registry, &selection, 1);
+            match record(&reports, answer) {
+                Ok(()) => failures.is_empty(),
+                Err(error) => {
+                    eprintln!("Failed to record answers: {}", error);
+                    false
+                }
+            }
+        }
+    };
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Part, Solution};
+
+    use super::*;
+
+    struct Counts;
+
+    impl Solution for Counts {
+        const DAY: u8 = 1;
+
+        type Input = usize;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &Self::Input) -> Self::Part1 {
+            *lines
+        }
+
+        fn part2(lines: &Self::Input) -> Self::Part2 {
+            lines * 2
+        }
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 2;
+
+        type Input = ();
+        type Part1 = u8;
+        type Part2 = u8;
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> Self::Part1 {
+            panic!("not solved")
+        }
+
+        fn part2(_input: &Self::Input) -> Self::Part2 {
+            panic!("not solved")
+        }
+    }
+
+    #[test]
+    fn generated_inputs_can_be_solved() {
+        let registry = registry();
+
+        for solution in registry.iter() {
+            for (seed, size) in [(1, Size { n: 1, m: 1 }), (2, Size { n: 60, m: 40 })] {
+                let Some(input) = solution.generate(&mut Rng::new(seed), size) else {
+                    continue;
+                };
+                assert_eq!(
+                    InputWarning::check(input.as_bytes(), solution.padded_lines()),
+                    [],
+                    "day {}",
+                    solution.day()
+                );
+                assert_eq!(
+                    solution.generate(&mut Rng::new(seed), size).as_ref(),
+                    Some(&input)
+                );
+
+                let report = run_day(solution, input.as_bytes(), &Part::ALL)
+                    .unwrap_or_else(|error| panic!("day {}: {}", solution.day(), error));
+                assert_eq!(report.parts.len(), 2);
+            }
+        }
+    }
+
+    #[test]
+    fn a_panicking_day_does_not_stop_the_others() {
+        let mut registry = Registry::new();
+        registry.register::<Panics>();
+        registry.register::<Counts>();
+
+        let selection = Selection {
+            days: Days::All,
+            part: Some(Part::One),
+            input: Some(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string()),
+        };
+
+        let (reports, failures) = solve(&registry, &selection, 2);
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, 2);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].day, 1);
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::{Duration, Instant};
+
+use common::{DynSolution, InputHash, ParseError, Part};
+
+pub struct PartResult {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+pub struct DayReport {
+    pub day: u8,
+    pub input_hash: InputHash,
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+pub fn run_day(
+    solution: &dyn DynSolution,
+    input: &[u8],
+    parts: &[Part],
+) -> Result<DayReport, ParseError> {
+    let start = Instant::now();
+    let parsed = solution.parse(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => solution.part1(parsed.as_ref()),
+                Part::Two => solution.part2(parsed.as_ref()),
+            };
+
+            PartResult {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(DayReport {
+        day: solution.day(),
+        input_hash: InputHash::of(input),
+        parse,
+        parts,
+    })
+}
+
+pub fn answer_width(reports: &[DayReport]) -> usize {
+    reports
+        .iter()
+        .flat_map(|report| report.parts.iter())
+        .flat_map(|part| part.answer.lines())
+        .map(|line| line.chars().count())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default()
+}
+
+/// Prints every answer with its timing. The total adds up the time spent in
+/// each phase, while `wall_time` is how long solving took from start to end,
+/// which is shorter when days ran in parallel.
+pub fn print_table(reports: &[DayReport], wall_time: Duration) {
+    let width = answer_width(reports);
+
+    println!(
+        "{:>3}  {:>4}  {:<width$}  {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+
+    let mut total = Duration::ZERO;
+
+    for report in reports {
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {:>12}",
+            report.day,
+            "-",
+            "(parse)",
+            format!("{:?}", report.parse)
+        );
+        total += report.parse;
+
+        for part in &report.parts {
+            let mut lines = part.answer.lines();
+            println!(
+                "{:>3}  {:>4}  {:<width$}  {:>12}",
+                report.day,
+                part.part,
+                lines.next().unwrap_or_default(),
+                format!("{:?}", part.elapsed)
+            );
+
+            for line in lines {
+                println!("{:>3}  {:>4}  {}", "", "", line);
+            }
+
+            total += part.elapsed;
+        }
+    }
+
+    println!("Total: {:?}", total);
+    println!("Wall time: {:?}", wall_time);
+}
@@ -0,0 +1,148 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::project_dir;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_RS: &str = include_str!("../templates/lib.rs.template");
+const MAIN_RS: &str = include_str!("../templates/main.rs.template");
+
+/// Creates the `dayNN` crate for `day` and adds it to the workspace members,
+/// the dependencies of `aoc` and the registry in `aoc`'s `main.rs`.
+pub fn new_day(day: u8) -> Result<(), String> {
+    let project = format!("day{:02}", day);
+    let dir = project_dir(&project);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Work out every edit before touching the disk, so a workspace that
+    // cannot be edited is left as it was.
+    let edit = |path: PathBuf, line: String, marker: &str| {
+        let contents =
+            fs::read_to_string(&path).map_err(|error| format!("{}: {}", path.display(), error))?;
+        match insert_day(&contents, day, line, marker) {
+            Some(contents) => Ok((path, contents)),
+            None => Err(format!(
+                "Could not find where to add {} in {}",
+                project,
+                path.display()
+            )),
+        }
+    };
+
+    let aoc = project_dir("aoc");
+    let edits = [
+        edit(
+            project_dir("Cargo.toml"),
+            format!("    \"{}\",", project),
+            "\",",
+        )?,
+        edit(
+            aoc.join("Cargo.toml"),
+            format!("{} = {{ path = \"../{}\" }}", project, project),
+            " = { path",
+        )?,
+        edit(
+            aoc.join("src").join("main.rs"),
+            format!("    {}::register(&mut registry);", project),
+            "::register(",
+        )?,
+    ];
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|error| format!("{}: {}", path.display(), error))
+    };
+
+    fs::create_dir_all(dir.join("src")).map_err(|error| format!("{}: {}", dir.display(), error))?;
+    write(&dir.join("Cargo.toml"), &render(CARGO_TOML, day))?;
+    write(&dir.join("src").join("lib.rs"), &render(LIB_RS, day))?;
+    write(&dir.join("src").join("main.rs"), &render(MAIN_RS, day))?;
+    write(&dir.join("input.txt"), "")?;
+    write(&dir.join("example.txt"), "")?;
+
+    for (path, contents) in edits {
+        write(&path, &contents)?;
+    }
+
+    Ok(())
+}
+
+/// Fills in the placeholders of a template for `day`.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{project}}", &format!("day{:02}", day))
+        .replace("{{Type}}", &format!("Day{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds `line` to the run of lines that mention other days, keeping them in
+/// order. A line belongs to the run if it starts with `dayNN`, optionally
+/// quoted, followed by `marker`. Returns `None` if there is no such line.
+fn insert_day(contents: &str, day: u8, line: String, marker: &str) -> Option<String> {
+    let day_of = |line: &str| {
+        let rest = line
+            .trim_start()
+            .trim_start_matches('"')
+            .strip_prefix("day")?;
+        let (digits, rest) = rest.split_at_checked(2)?;
+        if !rest.starts_with(marker) {
+            return None;
+        }
+        digits.parse::<u8>().ok()
+    };
+
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let last = lines.iter().rposition(|line| day_of(line).is_some())?;
+    let position = lines
+        .iter()
+        .position(|line| day_of(line).is_some_and(|other| other > day))
+        .unwrap_or(last + 1);
+
+    lines.insert(position, &line);
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    Some(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_days_in_order() {
+        let members = "members = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n]\n";
+
+        assert_eq!(
+            insert_day(members, 2, "    \"day02\",".to_string(), "\","),
+            Some(
+                "members = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n    \"day03\",\n]\n"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            insert_day(members, 4, "    \"day04\",".to_string(), "\","),
+            Some(
+                "members = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n    \"day04\",\n]\n"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            insert_day("[dependencies]\n", 4, String::new(), "\","),
+            None
+        );
+    }
+
+    #[test]
+    fn renders_templates() {
+        let lib = render(LIB_RS, 11);
+
+        assert!(lib.contains("pub struct Day11;"));
+        assert!(lib.contains("const DAY: u8 = 11;"));
+        assert!(!lib.contains("{{"));
+        assert!(render(CARGO_TOML, 11).contains("name = \"day11\""));
+    }
+}
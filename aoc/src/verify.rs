@@ -0,0 +1,72 @@
+use common::{Answers, Status};
+
+use crate::runner::DayReport;
+
+/// Compares every answer against the recorded ones and prints a status per
+/// part. Returns whether no part failed.
+///
+/// A wrong answer for an input whose fingerprint differs from the recorded
+/// one is reported as `input` rather than `FAIL`, since the input is more
+/// likely to be at fault than the code.
+pub fn print_verification(reports: &[DayReport]) -> bool {
+    let mut passed = true;
+
+    println!("{:>3}  {:>4}  {:<8}  Answer", "Day", "Part", "Status");
+
+    for report in reports {
+        let project = format!("day{:02}", report.day);
+        let answers = match Answers::load(&project) {
+            Ok(answers) => answers,
+            Err(error) => {
+                eprintln!("Failed to read answers: {}", error);
+                passed = false;
+                continue;
+            }
+        };
+
+        let other_input = answers
+            .input
+            .filter(|&recorded| recorded != report.input_hash);
+
+        for part in &report.parts {
+            let status = answers.check(part.part, &part.answer);
+            let label = match status {
+                Status::Pass => "pass",
+                Status::Fail { .. } if other_input.is_some() => "input",
+                Status::Fail { .. } => "FAIL",
+                Status::Unknown => "unknown",
+            };
+
+            print_lines(
+                &format!("{:>3}  {:>4}  {:<8}  ", report.day, part.part, label),
+                &part.answer,
+            );
+
+            if let Status::Fail { expected } = status {
+                print_lines(
+                    &format!("{:>3}  {:>4}  {:<8}  ", "", "", "expected"),
+                    &expected,
+                );
+                passed = false;
+            }
+        }
+
+        if let Some(recorded) = other_input {
+            println!(
+                "{:>3}  {:>4}  {:<8}  input {} was recorded, this one is {}",
+                "", "", "note", recorded, report.input_hash
+            );
+        }
+    }
+
+    passed
+}
+
+fn print_lines(prefix: &str, answer: &str) {
+    let mut lines = answer.lines();
+    println!("{}{}", prefix, lines.next().unwrap_or_default());
+
+    for line in lines {
+        println!("{:width$}{}", "", line, width = prefix.len());
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+};
+
+use crate::{project_dir, InputHash, Part};
+
+/// Answers that were accepted for a day, stored in `dayNN/answers.txt`.
+///
+/// Each line holds one part as `part1: <answer>`. Backslashes and newlines in
+/// an answer are escaped so multi-line answers such as day 10's CRT fit on a
+/// single line. An `input: <hash>` line records which input the answers
+/// belong to, so a wrong input can be told apart from a wrong solution.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    pub input: Option<InputHash>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn path(project: &str) -> PathBuf {
+        project_dir(project).join("answers.txt")
+    }
+
+    /// Loads the recorded answers, treating a missing file as no answers.
+    pub fn load(project: &str) -> io::Result<Self> {
+        let path = Self::path(project);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents).map_err(|error| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), error),
+                )
+            }),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn save(&self, project: &str) -> io::Result<()> {
+        fs::write(Self::path(project), self.to_string())
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Status {
+        match self.get(part) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+            },
+            None => Status::Unknown,
+        }
+    }
+
+    fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(": ")
+                .ok_or_else(|| format!("line {}: expected '<key>: <value>'", i + 1))?;
+
+            match key {
+                "input" => {
+                    let hash = value
+                        .parse()
+                        .map_err(|error| format!("line {}: {}", i + 1, error))?;
+                    answers.input = Some(hash);
+                }
+                "part1" => answers.set(Part::One, unescape(value)),
+                "part2" => answers.set(Part::Two, unescape(value)),
+                _ => return Err(format!("line {}: unknown key {:?}", i + 1, key)),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(input) = self.input {
+            writeln!(f, "input: {}", input)?;
+        }
+
+        for part in Part::ALL {
+            if let Some(answer) = self.get(part) {
+                writeln!(f, "part{}: {}", part, escape(answer))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(value: &str) -> String {
+    let mut answer = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(char) = chars.next() {
+        match (char, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                answer.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                answer.push('\\');
+                chars.next();
+            }
+            _ => answer.push(char),
+        }
+    }
+
+    answer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_line_answers_round_trip() {
+        let answers = Answers {
+            input: Some(InputHash::of(b"input")),
+            part1: Some("12\\n34".to_string()),
+            part2: Some("#..#\n.##.".to_string()),
+        };
+
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn check_reports_status() {
+        let answers = Answers {
+            part1: Some("42".to_string()),
+            ..Answers::default()
+        };
+
+        assert_eq!(answers.check(Part::One, "42"), Status::Pass);
+        assert_eq!(
+            answers.check(Part::One, "43"),
+            Status::Fail {
+                expected: "42".to_string()
+            }
+        );
+        assert_eq!(answers.check(Part::Two, "42"), Status::Unknown);
+    }
+}
@@ -0,0 +1,176 @@
+//! Byte-oriented access to inputs, for inputs too large to comfortably
+//! allocate a `String` per line for.
+
+use std::io::{self, Read};
+
+use crate::ParseError;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Splits `input` into lines without copying, dropping the `\n` or `\r\n` at
+/// the end of each, the same way [`str::lines`] does.
+pub fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let lines = match input.is_empty() {
+        true => None,
+        false => {
+            let input = input.strip_suffix(b"\n").unwrap_or(input);
+            Some(input.split(|&byte| byte == b'\n'))
+        }
+    };
+
+    lines
+        .into_iter()
+        .flatten()
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+/// Parses an unsigned decimal number made of ASCII digits only.
+pub fn unsigned<T: std::str::FromStr>(digits: &[u8]) -> Option<T> {
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    std::str::from_utf8(digits).ok()?.parse().ok()
+}
+
+/// Converts `input` to text, reporting where the first invalid byte is.
+pub fn to_str(input: &[u8]) -> Result<&str, ParseError> {
+    std::str::from_utf8(input).map_err(|error| {
+        let valid = &input[..error.valid_up_to()];
+        let line_start = valid
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |i| i + 1);
+        let column = String::from_utf8_lossy(&valid[line_start..])
+            .chars()
+            .count()
+            + 1;
+        let found = format!("byte {:#04x}", input[error.valid_up_to()]);
+
+        ParseError::new(column, "UTF-8 text", Some(&found))
+            .with_line(valid.iter().filter(|&&byte| byte == b'\n').count() + 1)
+    })
+}
+
+/// Reads lines from `reader` a chunk at a time, handing out each line as a
+/// slice of its internal buffer instead of allocating it.
+///
+/// Only a line that straddles two chunks is moved, so memory use stays at
+/// about one chunk however large the input is.
+pub struct LineReader<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    start: usize,
+    end: usize,
+    /// How many bytes after `start` are known not to contain a newline.
+    searched: usize,
+    done: bool,
+}
+
+impl<R: Read> LineReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_chunk_size(reader, CHUNK_SIZE)
+    }
+
+    pub fn with_chunk_size(reader: R, chunk_size: usize) -> Self {
+        Self {
+            reader,
+            buffer: vec![0; chunk_size.max(1)],
+            start: 0,
+            end: 0,
+            searched: 0,
+            done: false,
+        }
+    }
+
+    /// The next line without its line ending, or `None` at the end of the
+    /// input.
+    pub fn next_line(&mut self) -> io::Result<Option<&[u8]>> {
+        loop {
+            let unsearched = &self.buffer[self.start + self.searched..self.end];
+            if let Some(i) = unsearched.iter().position(|&byte| byte == b'\n') {
+                let line = self.start..self.start + self.searched + i;
+                self.start = line.end + 1;
+                self.searched = 0;
+                return Ok(Some(trim_cr(&self.buffer[line])));
+            }
+            self.searched = self.end - self.start;
+
+            if self.done {
+                if self.start == self.end {
+                    return Ok(None);
+                }
+
+                let line = self.start..self.end;
+                self.start = self.end;
+                self.searched = 0;
+                return Ok(Some(trim_cr(&self.buffer[line])));
+            }
+
+            self.fill()?;
+        }
+    }
+
+    /// Moves the unfinished line to the front of the buffer, growing it if
+    /// the line fills it already, and reads the next chunk behind it.
+    fn fill(&mut self) -> io::Result<()> {
+        self.buffer.copy_within(self.start..self.end, 0);
+        self.end -= self.start;
+        self.start = 0;
+
+        if self.end == self.buffer.len() {
+            self.buffer.resize(self.buffer.len() * 2, 0);
+        }
+
+        match self.reader.read(&mut self.buffer[self.end..]) {
+            Ok(0) => self.done = true,
+            Ok(read) => self.end += read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+
+        Ok(())
+    }
+}
+
+fn trim_cr(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_lines_like_str() {
+        for input in ["", "\n", "a", "a\n", "a\r\nb\n\nc", "\n\nx\n"] {
+            let expected = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+            assert_eq!(lines(input.as_bytes()).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn reads_lines_across_chunks() {
+        let input = "first line\r\nsecond\n\na much longer third line\nlast";
+
+        for chunk_size in [1, 3, 8, 1024] {
+            let mut reader = LineReader::with_chunk_size(input.as_bytes(), chunk_size);
+            let mut read = Vec::new();
+            while let Some(line) = reader.next_line().unwrap() {
+                read.push(String::from_utf8(line.to_vec()).unwrap());
+            }
+
+            assert_eq!(read, input.lines().collect::<Vec<_>>(), "{}", chunk_size);
+        }
+    }
+
+    #[test]
+    fn parses_numbers_and_text() {
+        assert_eq!(unsigned::<u32>(b"1234"), Some(1234));
+        assert_eq!(unsigned::<u32>(b"+1"), None);
+        assert_eq!(unsigned::<u8>(b"256"), None);
+
+        let error = to_str(b"ok\nb\xffd").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+}
@@ -0,0 +1,83 @@
+use std::{error::Error, fmt::Display};
+
+/// A malformed puzzle input, pointing at the offending place in the input.
+///
+/// `line` and `column` are 1-based. Parsers that only see a single line leave
+/// `line` at 0 and let the caller fill it in with [`ParseError::with_line`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(column: usize, expected: impl Into<String>, found: Option<&str>) -> Self {
+        Self {
+            line: 0,
+            column,
+            expected: expected.into(),
+            found: found.map(str::to_string),
+        }
+    }
+
+    /// An error for `found`, which must be a slice of `source`.
+    pub fn at(source: &str, found: &str, expected: impl Into<String>) -> Self {
+        Self::new(offset(source, found) + 1, expected, Some(found))
+    }
+
+    /// An error for input that ended where `expected` should have been.
+    pub fn end_of(source: &str, expected: impl Into<String>) -> Self {
+        Self::new(source.chars().count() + 1, expected, None)
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Moves an error produced for `part` so its column is relative to
+    /// `source`, which `part` must be a slice of.
+    pub fn within(mut self, source: &str, part: &str) -> Self {
+        self.column += offset(source, part);
+        self
+    }
+}
+
+fn offset(source: &str, part: &str) -> usize {
+    let bytes = (part.as_ptr() as usize)
+        .checked_sub(source.as_ptr() as usize)
+        .filter(|bytes| *bytes <= source.len())
+        .expect("part is not a slice of source");
+    source[..bytes].chars().count()
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+
+        match &self.found {
+            Some(found) => write!(f, ", found {:?}", found),
+            None => write!(f, ", found end of line"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every line of `input` with `parse`, tagging errors with their line.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|error| error.with_line(i + 1)))
+        .collect()
+}
@@ -0,0 +1,124 @@
+//! Reproducible random inputs for stress-testing the solutions.
+
+use std::{ops::RangeInclusive, str::FromStr};
+
+/// A small SplitMix64 generator. The same seed always produces the same
+/// numbers, on every platform.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. Panics if `n` is 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot pick a number below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// A number in `range`, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        let span = end.abs_diff(start) as u128 + 1;
+        start.wrapping_add(((self.next_u64() as u128 * span) >> 64) as i64)
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let fraction = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        fraction < probability
+    }
+
+    /// A random element of `items`. Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// How large a generated input should be.
+///
+/// `n` is the main measure of a day's input, such as the number of elves or
+/// moves. Days with a second dimension, like day 8's grid, use `m` for it.
+/// Written as `n` or `nxm`, where `n` alone means `nxn`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Size {
+    pub n: usize,
+    pub m: usize,
+}
+
+impl FromStr for Size {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parse = |part: &str| match part.parse::<usize>() {
+            Ok(number) if number > 0 => Ok(number),
+            _ => Err(format!("Invalid size: {}", value)),
+        };
+
+        match value.split_once('x') {
+            Some((n, m)) => Ok(Self {
+                n: parse(n)?,
+                m: parse(m)?,
+            }),
+            None => {
+                let n = parse(value)?;
+                Ok(Self { n, m: n })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_reproducible() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(1);
+
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3..=3)));
+            assert!(rng.below(5) < 5);
+        }
+        assert_eq!(rng.range(i64::MIN..=i64::MIN), i64::MIN);
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!("10".parse(), Ok(Size { n: 10, m: 10 }));
+        assert_eq!("10x4".parse(), Ok(Size { n: 10, m: 4 }));
+        assert!("0".parse::<Size>().is_err());
+        assert!("4x".parse::<Size>().is_err());
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{bytes, geom::Direction, ParseError};
+
+/// A cell in a [`Grid`] as `(row, column)`, counted from the top left.
+pub type Position = (usize, usize);
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from cells in row-major order.
+    ///
+    /// Panics if the cells do not fill a whole number of rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = match width {
+            0 => 0,
+            _ => cells.len() / width,
+        };
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells do not fit in rows of {}",
+            cells.len(),
+            width
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, converting each character with `cell`.
+    ///
+    /// Characters that `cell` rejects and rows that differ in width from the
+    /// first are reported as errors, with `expected` describing a valid cell.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for (i, line) in input.lines().enumerate() {
+            for (j, char) in line.char_indices() {
+                match cell(char) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        let found = &line[j..j + char.len_utf8()];
+                        return Err(ParseError::at(line, found, expected).with_line(i + 1));
+                    }
+                }
+            }
+
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    let expected = format!("a row of {} cells", width);
+                    return Err(ParseError::at(line, line, expected).with_line(i + 1));
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(Self::from_vec(width.unwrap_or_default(), cells))
+    }
+
+    /// Like [`Grid::parse`], but converts each byte of `input` with `cell`
+    /// instead of each character, without copying any of the rows.
+    pub fn parse_bytes(
+        input: &[u8],
+        expected: &str,
+        mut cell: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for (i, line) in bytes::lines(input).enumerate() {
+            for (j, &byte) in line.iter().enumerate() {
+                match cell(byte) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        let found = String::from_utf8_lossy(&line[j..j + 1]);
+                        return Err(ParseError::new(j + 1, expected, Some(&found)).with_line(i + 1));
+                    }
+                }
+            }
+
+            match width {
+                None => width = Some(line.len()),
+                Some(width) if width != line.len() => {
+                    let expected = format!("a row of {} cells", width);
+                    let found = String::from_utf8_lossy(line);
+                    return Err(ParseError::new(1, expected, Some(&found)).with_line(i + 1));
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(Self::from_vec(width.unwrap_or_default(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): Position) -> Option<&T> {
+        if row < self.height && column < self.width {
+            self.cells.get(row * self.width + column)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, column): Position) -> Option<&mut T> {
+        if row < self.height && column < self.width {
+            self.cells.get_mut(row * self.width + column)
+        } else {
+            None
+        }
+    }
+
+    /// The position one step from `position` in `direction`, if it is inside
+    /// the grid.
+    pub fn step(&self, (row, column): Position, direction: Direction) -> Option<Position> {
+        let vector = direction.vector();
+        let row = row.checked_add_signed(vector.y as isize)?;
+        let column = column.checked_add_signed(vector.x as isize)?;
+
+        if row < self.height && column < self.width {
+            Some((row, column))
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row < self.height {
+            Some(&self.cells[row * self.width..(row + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        let rows = if column < self.width { self.height } else { 0 };
+        (0..rows).map(move |row| &self.cells[row * self.width + column])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Walks from `start` towards the edge of the grid in `direction`. The
+    /// starting cell itself is not included.
+    pub fn walk(
+        &self,
+        start: Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = (Position, &T)> {
+        std::iter::successors(self.step(start, direction), move |&position| {
+            self.step(position, direction)
+        })
+        .map(|position| (position, &self[position]))
+    }
+
+    /// The up to four cells that share an edge with `position`.
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = (Position, &T)> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+            .map(|position| (position, &self[position]))
+    }
+
+    /// The up to eight cells that share an edge or a corner with `position`.
+    pub fn neighbours_with_diagonals(
+        &self,
+        (row, column): Position,
+    ) -> impl Iterator<Item = (Position, &T)> {
+        (-1..=1)
+            .flat_map(|row_offset| (-1..=1).map(move |column_offset| (row_offset, column_offset)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(row_offset, column_offset)| {
+                let position = (
+                    row.checked_add_signed(row_offset)?,
+                    column.checked_add_signed(column_offset)?,
+                );
+                self.get(position).map(|cell| (position, cell))
+            })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, "a digit", |char| char.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((1, 2)), Some(&6));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+    }
+
+    #[test]
+    fn reports_bad_cells_and_ragged_rows() {
+        let error = Grid::parse("12\n1x\n", "a digit", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = Grid::parse("12\n123\n", "a digit", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let digit = |byte: u8| byte.is_ascii_digit().then(|| byte - b'0');
+        let error = Grid::parse_bytes(b"12\n1x\n", "a digit", digit).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            Grid::parse_bytes(b"12\r\n34\r\n", "a digit", digit)
+                .unwrap()
+                .get((1, 1)),
+            Some(&4)
+        );
+    }
+
+    #[test]
+    fn walks_to_the_edge() {
+        let grid = digits("123\n456\n789\n");
+
+        let walk = |direction| {
+            grid.walk((1, 1), direction)
+                .map(|(_, cell)| *cell)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(walk(Direction::Up), [2]);
+        assert_eq!(walk(Direction::Down), [8]);
+        assert_eq!(walk(Direction::Left), [4]);
+        assert_eq!(grid.walk((0, 0), Direction::Right).count(), 2);
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = digits("123\n456\n789\n");
+
+        assert_eq!(grid.neighbours((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_with_diagonals((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours_with_diagonals((1, 1)).count(), 8);
+    }
+}
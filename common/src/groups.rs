@@ -0,0 +1,134 @@
+use std::io::{self, BufRead};
+
+use crate::{bytes, ParseError};
+
+/// Splits the lines of `reader` into groups separated by blank lines, such as
+/// one elf's snacks or one section of a multi-part input.
+///
+/// Runs of blank lines count as a single separator and never produce an empty
+/// group. Reading stops at the first error, such as a line that is not UTF-8.
+pub fn read_groups(reader: impl BufRead) -> impl Iterator<Item = io::Result<Vec<String>>> {
+    let mut lines = reader.lines();
+    let mut failed = false;
+
+    std::iter::from_fn(move || {
+        let mut group = Vec::new();
+
+        while !failed {
+            match lines.next() {
+                Some(Ok(line)) if line.trim().is_empty() => {
+                    if !group.is_empty() {
+                        break;
+                    }
+                }
+                Some(Ok(line)) => group.push(line),
+                Some(Err(error)) => {
+                    failed = true;
+                    return Some(Err(error));
+                }
+                None => break,
+            }
+        }
+
+        (!group.is_empty()).then_some(Ok(group))
+    })
+}
+
+/// Parses every group of `input` with `parse`, reporting input that is not
+/// UTF-8 as a [`ParseError`].
+///
+/// `parse` numbers lines from the start of its group, the same way
+/// [`parse_lines`](crate::parse_lines) does, and the line numbers in its
+/// errors are shifted to count from the start of the whole input.
+pub fn parse_groups<'a, T>(
+    input: &'a [u8],
+    parse: impl FnMut(&[&'a str]) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    parse_numbered(numbered_groups(bytes::to_str(input)?.lines()), parse)
+}
+
+/// Like [`parse_groups`], but splits `input` without copying its lines.
+pub fn parse_byte_groups<'a, T>(
+    input: &'a [u8],
+    parse: impl FnMut(&[&'a [u8]]) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    parse_numbered(numbered_groups(bytes::lines(input)), parse)
+}
+
+fn parse_numbered<L, T>(
+    groups: impl Iterator<Item = (usize, Vec<L>)>,
+    mut parse: impl FnMut(&[L]) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    groups
+        .map(|(first_line, group)| {
+            parse(&group).map_err(|error| {
+                let line = first_line + error.line.saturating_sub(1);
+                error.with_line(line)
+            })
+        })
+        .collect()
+}
+
+/// Groups `lines`, numbering each group with its first line, counted from 1.
+fn numbered_groups<L: AsRef<[u8]>>(
+    lines: impl Iterator<Item = L>,
+) -> impl Iterator<Item = (usize, Vec<L>)> {
+    let mut lines = lines.enumerate().peekable();
+    let blank = |line: &L| line.as_ref().trim_ascii().is_empty();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(|(_, line)| blank(line)).is_some() {}
+
+        let (i, line) = lines.next()?;
+        let mut group = vec![line];
+        while let Some((_, line)) = lines.next_if(|(_, line)| !blank(line)) {
+            group.push(line);
+        }
+
+        Some((i + 1, group))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_blank_lines() {
+        let groups = read_groups("\n1\n2\n\n \n3\n".as_bytes())
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(groups, [vec!["1", "2"], vec!["3"]]);
+    }
+
+    #[test]
+    fn reports_lines_of_the_whole_input() {
+        let error = parse_groups(b"1\n\n2\nx\n", |group| {
+            for (i, line) in group.iter().enumerate() {
+                line.parse::<u32>()
+                    .map_err(|_| ParseError::at(line, line, "a number").with_line(i + 1))?;
+            }
+            Ok(())
+        })
+        .unwrap_err();
+
+        assert_eq!(error.line, 4);
+    }
+
+    #[test]
+    fn splits_bytes_the_same_way() {
+        let groups = parse_byte_groups(b"\n1\n2\n\n \n3\n", |group| Ok(group.len())).unwrap();
+        assert_eq!(groups, [2, 1]);
+    }
+
+    #[test]
+    fn reports_input_that_is_not_utf8() {
+        let mut groups = read_groups(&b"1\n\n\xff\n"[..]);
+        assert_eq!(groups.next().unwrap().unwrap(), ["1"]);
+        assert!(groups.next().unwrap().is_err());
+        assert!(groups.next().is_none());
+
+        let error = parse_groups(b"1\n\xff\n", |_| Ok(())).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
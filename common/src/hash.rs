@@ -0,0 +1,53 @@
+use std::{fmt::Display, str::FromStr};
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hash of an input, good enough to tell two inputs apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InputHash(pub u64);
+
+impl InputHash {
+    pub fn of(input: &[u8]) -> Self {
+        Self(input.iter().fold(OFFSET_BASIS, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(PRIME)
+        }))
+    }
+}
+
+impl Display for InputHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl FromStr for InputHash {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.len() != 16 || !value.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(format!("expected 16 hex digits, found {:?}", value));
+        }
+
+        Ok(Self(u64::from_str_radix(value, 16).unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_reference_values() {
+        assert_eq!(InputHash::of(b"").to_string(), "cbf29ce484222325");
+        assert_eq!(InputHash::of(b"a").to_string(), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn round_trips_through_strings() {
+        let hash = InputHash::of(b"input");
+        assert_eq!(hash.to_string().parse(), Ok(hash));
+        assert!("+f63dc4c8601ec8c".parse::<InputHash>().is_err());
+        assert!("af63".parse::<InputHash>().is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+    process,
+};
+
+use crate::{project_dir, InputWarning, Solution};
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// The `input.txt` committed next to the given day crate.
+    pub fn default_for(project: &str) -> Self {
+        Self::File(project_dir(project).join("input.txt"))
+    }
+
+    /// Resolves an `--input` value, where `-` means stdin and no value means
+    /// the day's default input file.
+    pub fn from_arg(project: &str, arg: Option<&str>) -> Self {
+        match arg {
+            None => Self::default_for(project),
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Self::File(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(io::Error::new(error.kind(), format!("{}: {}", self, error))),
+            },
+            Self::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    /// Reads the whole input as bytes, leaving it to the day to decide
+    /// whether it needs to be text.
+    pub fn read_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut input = Vec::new();
+        self.open()?
+            .read_to_end(&mut input)
+            .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", self, error)))?;
+        Ok(input)
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// Finds the value of `--input`/`-i` among command line arguments.
+pub fn input_arg(args: impl IntoIterator<Item = String>) -> Result<Option<String>, String> {
+    let mut args = args.into_iter();
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-i" | "--input" => {
+                input = Some(args.next().ok_or("Missing value for --input")?);
+            }
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    Ok(input)
+}
+
+/// Removes `flag` and the value after it from `args`, for day binaries that
+/// take options of their own. Returns the value if the flag was given.
+pub fn take_arg(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    let Some(i) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+
+    args.remove(i);
+    if i == args.len() {
+        return Err(format!("Missing value for {}", flag));
+    }
+    Ok(Some(args.remove(i)))
+}
+
+/// Loads the input for a day binary, honouring `--input <path|->` on the
+/// command line. Exits the process if the input cannot be read.
+pub fn load_input(project: &str) -> Vec<u8> {
+    load_input_from_args(project, std::env::args().skip(1))
+}
+
+/// Like [`load_input`], for a day binary that takes arguments of its own and
+/// passes on the rest.
+pub fn load_input_from_args(project: &str, args: impl IntoIterator<Item = String>) -> Vec<u8> {
+    input_source_from_args(project, args)
+        .read_bytes()
+        .unwrap_or_else(|error| {
+            eprintln!("Failed to read input: {}", error);
+            process::exit(1);
+        })
+}
+
+/// Where `--input <path|->` points among the arguments left for a day
+/// binary, for one that reads its input itself. Exits the process on any
+/// other argument.
+pub fn input_source_from_args(
+    project: &str,
+    args: impl IntoIterator<Item = String>,
+) -> InputSource {
+    match input_arg(args) {
+        Ok(arg) => InputSource::from_arg(project, arg.as_deref()),
+        Err(error) => {
+            eprintln!("{}\n\nUsage: {} [--input <path|->]", error, project);
+            process::exit(2);
+        }
+    }
+}
+
+/// Parses a day binary's input, exiting the process if it is malformed.
+/// Signs of a mangled input are printed as warnings.
+pub fn parse_input<S: Solution>(input: &[u8]) -> S::Input {
+    for warning in InputWarning::check(input, S::PADDED_LINES) {
+        eprintln!("Warning: {}", warning);
+    }
+
+    S::parse_bytes(input).unwrap_or_else(|error| {
+        eprintln!("Invalid input: {}", error);
+        process::exit(1);
+    })
+}
@@ -0,0 +1,266 @@
+use std::{
+    fmt::Display,
+    io::{self, Read},
+};
+
+/// Something about an input file that suggests it was mangled on its way from
+/// the puzzle page, e.g. by an editor or by copying it through a browser.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputWarning {
+    CrlfLineEndings,
+    MissingTrailingNewline,
+    /// Spaces or tabs at the end of `lines` lines, the first being
+    /// `first_line`.
+    TrailingWhitespace {
+        first_line: usize,
+        lines: usize,
+    },
+    /// Blank lines after the last line of the input.
+    TrailingBlankLines,
+}
+
+impl InputWarning {
+    /// Inspects `input` for the usual signs of mangling. Trailing whitespace
+    /// is only reported when `padded_lines` is false, since some inputs pad
+    /// their lines on purpose.
+    pub fn check(input: &[u8], padded_lines: bool) -> Vec<Self> {
+        let mut check = InputCheck::new(padded_lines);
+        check.feed(input);
+        check.finish()
+    }
+}
+
+/// [`InputWarning::check`] for an input that arrives a chunk at a time, so it
+/// never has to be held whole.
+#[derive(Clone, Debug)]
+pub struct InputCheck {
+    padded_lines: bool,
+    crlf: bool,
+    /// The last byte fed, if any.
+    last: Option<u8>,
+    /// Lines finished so far.
+    lines: usize,
+    /// The last two bytes of the unfinished line.
+    tail: [Option<u8>; 2],
+    /// Whether the unfinished line is blank so far.
+    blank: bool,
+    /// Whether the last finished line was blank.
+    last_line_blank: bool,
+    padded: Option<(usize, usize)>,
+}
+
+impl InputCheck {
+    pub fn new(padded_lines: bool) -> Self {
+        Self {
+            padded_lines,
+            crlf: false,
+            last: None,
+            lines: 0,
+            tail: [None; 2],
+            blank: true,
+            last_line_blank: false,
+            padded: None,
+        }
+    }
+
+    pub fn feed(&mut self, chunk: &[u8]) {
+        for &byte in chunk {
+            if byte == b'\n' {
+                self.crlf |= self.last == Some(b'\r');
+                self.end_line();
+            } else {
+                self.tail = [self.tail[1], Some(byte)];
+                self.blank &= byte.is_ascii_whitespace();
+            }
+            self.last = Some(byte);
+        }
+    }
+
+    /// Finishes the line being fed, which [`bytes::lines`](crate::bytes::lines)
+    /// would yield without a `\r` at its end.
+    fn end_line(&mut self) {
+        self.lines += 1;
+
+        let last = match self.tail {
+            [before, Some(b'\r')] => before,
+            [_, last] => last,
+        };
+        if matches!(last, Some(b' ' | b'\t')) {
+            let (first_line, lines) = self.padded.unwrap_or((self.lines, 0));
+            self.padded = Some((first_line, lines + 1));
+        }
+
+        self.last_line_blank = self.blank;
+        self.tail = [None; 2];
+        self.blank = true;
+    }
+
+    pub fn finish(mut self) -> Vec<InputWarning> {
+        let mut warnings = Vec::new();
+
+        let Some(last) = self.last else {
+            return warnings;
+        };
+        if last != b'\n' {
+            self.end_line();
+        }
+
+        if self.crlf {
+            warnings.push(InputWarning::CrlfLineEndings);
+        }
+
+        if last != b'\n' {
+            warnings.push(InputWarning::MissingTrailingNewline);
+        }
+
+        if let (false, Some((first_line, lines))) = (self.padded_lines, self.padded) {
+            warnings.push(InputWarning::TrailingWhitespace { first_line, lines });
+        }
+
+        if self.last_line_blank {
+            warnings.push(InputWarning::TrailingBlankLines);
+        }
+
+        warnings
+    }
+}
+
+/// Runs an [`InputCheck`] over everything read through it.
+pub struct CheckedReader<R> {
+    reader: R,
+    check: InputCheck,
+}
+
+impl<R: Read> CheckedReader<R> {
+    pub fn new(reader: R, padded_lines: bool) -> Self {
+        Self {
+            reader,
+            check: InputCheck::new(padded_lines),
+        }
+    }
+
+    /// Reads whatever is left, then reports on the whole input.
+    pub fn warnings(mut self) -> io::Result<Vec<InputWarning>> {
+        io::copy(&mut self, &mut io::sink())?;
+        Ok(self.check.finish())
+    }
+}
+
+impl<R: Read> Read for CheckedReader<R> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buffer)?;
+        self.check.feed(&buffer[..read]);
+        Ok(read)
+    }
+}
+
+impl Display for InputWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CrlfLineEndings => write!(f, "CRLF line endings"),
+            Self::MissingTrailingNewline => write!(f, "no newline at the end of the input"),
+            Self::TrailingWhitespace {
+                first_line,
+                lines: 1,
+            } => write!(f, "trailing whitespace on line {}", first_line),
+            Self::TrailingWhitespace { first_line, lines } => write!(
+                f,
+                "trailing whitespace on {} lines, starting at line {}",
+                lines, first_line
+            ),
+            Self::TrailingBlankLines => write!(f, "blank lines at the end of the input"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_input_has_no_warnings() {
+        assert_eq!(InputWarning::check(b"1\n\n2\n", false), []);
+        assert_eq!(InputWarning::check(b"", false), []);
+    }
+
+    #[test]
+    fn reports_mangled_input() {
+        assert_eq!(
+            InputWarning::check(b"1\r\n2", false),
+            [
+                InputWarning::CrlfLineEndings,
+                InputWarning::MissingTrailingNewline
+            ]
+        );
+        assert_eq!(
+            InputWarning::check(b"1 \n2\n3\t\n\n", false),
+            [
+                InputWarning::TrailingWhitespace {
+                    first_line: 1,
+                    lines: 2
+                },
+                InputWarning::TrailingBlankLines
+            ]
+        );
+        assert_eq!(InputWarning::check(b"  [A]  \n", true), []);
+    }
+
+    #[test]
+    fn checks_inputs_fed_in_chunks() {
+        let inputs: [&[u8]; 8] = [
+            b"1\r\n2",
+            b"1 \n2\n3\t\n\n",
+            b"1 \r\n\r\n",
+            b"a\r",
+            b"\n",
+            b" ",
+            b"x\n \n",
+            b"1\n\n2\n",
+        ];
+
+        for input in inputs {
+            let mut reader = CheckedReader::new(input, false);
+            let mut byte = [0];
+            while reader.read(&mut byte).unwrap() > 0 {}
+
+            assert_eq!(
+                reader.warnings().unwrap(),
+                reference_check(input),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    /// The checks as written against the whole input at once.
+    fn reference_check(input: &[u8]) -> Vec<InputWarning> {
+        let mut warnings = Vec::new();
+        if input.is_empty() {
+            return warnings;
+        }
+        if input.windows(2).any(|pair| pair == b"\r\n") {
+            warnings.push(InputWarning::CrlfLineEndings);
+        }
+        if !input.ends_with(b"\n") {
+            warnings.push(InputWarning::MissingTrailingNewline);
+        }
+
+        let lines = crate::bytes::lines(input).collect::<Vec<_>>();
+        let mut padded =
+            (1..=lines.len()).filter(|&line| matches!(lines[line - 1].last(), Some(b' ' | b'\t')));
+        if let Some(first_line) = padded.next() {
+            warnings.push(InputWarning::TrailingWhitespace {
+                first_line,
+                lines: padded.count() + 1,
+            });
+        }
+        if lines
+            .last()
+            .is_some_and(|line| line.trim_ascii().is_empty())
+        {
+            warnings.push(InputWarning::TrailingBlankLines);
+        }
+
+        warnings
+    }
+}
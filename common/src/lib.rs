@@ -1,18 +1,37 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    path::PathBuf,
+use std::{io::BufRead, path::PathBuf};
+
+mod answers;
+pub mod bytes;
+mod error;
+pub mod generate;
+pub mod geom;
+pub mod grid;
+mod groups;
+mod hash;
+mod input;
+mod integrity;
+pub mod parse;
+mod solution;
+
+pub use answers::{Answers, Status};
+pub use error::{parse_lines, ParseError};
+pub use groups::{parse_byte_groups, parse_groups, read_groups};
+pub use hash::InputHash;
+pub use input::{
+    input_arg, input_source_from_args, load_input, load_input_from_args, parse_input, take_arg,
+    InputSource,
 };
+pub use integrity::{CheckedReader, InputCheck, InputWarning};
+pub use solution::{DynSolution, Part, Registry, Solution};
 
-pub fn read_input(project: &str) -> BufReader<File> {
-    let mut project_root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    project_root.pop();
-    project_root.push(project);
-    project_root.push("input.txt");
-    let input = File::open(project_root).expect("Input file not found");
-    BufReader::new(input)
+/// The directory of a day crate, e.g. `day07`, inside the workspace.
+pub fn project_dir(project: &str) -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.pop();
+    path.push(project);
+    path
 }
 
-pub fn read_lines(reader: BufReader<File>) -> impl Iterator<Item = String> {
+pub fn read_lines(reader: impl BufRead) -> impl Iterator<Item = String> {
     reader.lines().map(|l| l.unwrap())
 }
@@ -0,0 +1,245 @@
+use std::{any::type_name, str::FromStr};
+
+use crate::ParseError;
+
+/// A cursor over a single line, with small parsers that consume from the
+/// front of it.
+///
+/// Token parsers such as [`Parser::keyword`] and [`Parser::unsigned`] skip
+/// leading whitespace, while [`Parser::literal`] matches exactly where the
+/// cursor is. Errors point at the next word of the line.
+#[derive(Clone)]
+pub struct Parser<'a> {
+    source: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            rest: source,
+        }
+    }
+
+    /// Runs `parse` on the whole of `source`, failing if anything is left
+    /// over afterwards.
+    pub fn parse_all<T>(
+        source: &'a str,
+        parse: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let mut parser = Self::new(source);
+        let value = parse(&mut parser)?;
+        parser.end()?;
+        Ok(value)
+    }
+
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.trim_start().is_empty()
+    }
+
+    /// An error at the cursor, showing the word that was found there.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let rest = self.rest.trim_start();
+        match rest.split_whitespace().next() {
+            Some(word) => ParseError::at(self.source, word, expected),
+            None => ParseError::end_of(self.source, expected),
+        }
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    /// Takes the longest prefix whose characters all match `predicate`.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let end = self
+            .rest
+            .char_indices()
+            .find(|(_, char)| !predicate(*char))
+            .map_or(self.rest.len(), |(i, _)| i);
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    /// Matches `token` exactly at the cursor.
+    pub fn literal(&mut self, token: &str) -> Result<(), ParseError> {
+        match self.rest.strip_prefix(token) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(self.error(format!("'{}'", token))),
+        }
+    }
+
+    /// The next run of non-whitespace characters.
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        match self.take_while(|char| !char.is_whitespace()) {
+            "" => Err(self.error(expected)),
+            word => Ok(word),
+        }
+    }
+
+    /// The next word, converted with its [`FromStr`] implementation. Columns
+    /// in the conversion error are made relative to the whole line.
+    pub fn parsed<T>(&mut self, expected: &str) -> Result<T, ParseError>
+    where
+        T: FromStr<Err = ParseError>,
+    {
+        let word = self.word(expected)?;
+        word.parse()
+            .map_err(|error: ParseError| error.within(self.source, word))
+    }
+
+    /// Matches `keyword` as a whole word.
+    pub fn keyword(&mut self, keyword: &str) -> Result<(), ParseError> {
+        self.one_of(&[(keyword, ())])
+    }
+
+    /// Matches one of the given words and returns the value paired with it.
+    pub fn one_of<T: Clone>(&mut self, choices: &[(&str, T)]) -> Result<T, ParseError> {
+        let start = self.rest;
+        self.skip_whitespace();
+        let word = self.take_while(|char| !char.is_whitespace());
+
+        match choices.iter().find(|(choice, _)| *choice == word) {
+            Some((_, value)) => Ok(value.clone()),
+            None => {
+                self.rest = start;
+                let names = choices
+                    .iter()
+                    .map(|(choice, _)| format!("'{}'", choice))
+                    .collect::<Vec<_>>();
+                Err(self.error(names.join(" or ")))
+            }
+        }
+    }
+
+    /// An integer made of ASCII digits, without a sign.
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let start = self.rest;
+        let digits = self.take_while(|char| char.is_ascii_digit());
+        self.number(start, digits)
+    }
+
+    /// An integer with an optional leading `-` or `+`.
+    pub fn signed<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let start = self.rest;
+        let sign = self.take_while(|char| char == '-' || char == '+').len();
+        let digits = self.take_while(|char| char.is_ascii_digit()).len();
+        let number = &start[..sign + digits];
+
+        if sign > 1 || digits == 0 {
+            self.rest = start;
+            return Err(self.error("an integer"));
+        }
+
+        self.number(start, number)
+    }
+
+    fn number<T: FromStr>(&mut self, start: &'a str, number: &'a str) -> Result<T, ParseError> {
+        if number.is_empty() {
+            self.rest = start;
+            return Err(self.error("an integer"));
+        }
+
+        number.parse().map_err(|_| {
+            let expected = format!("an integer that fits in {}", type_name::<T>());
+            ParseError::at(self.source, number, expected)
+        })
+    }
+
+    /// One or more items separated by `separator`.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+
+        while let Some(rest) = self.rest.strip_prefix(separator) {
+            self.rest = rest;
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Succeeds only if nothing but whitespace is left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tokens() {
+        let (amount, from, to) = Parser::parse_all("move 3 from 1 to 2", |p| {
+            p.keyword("move")?;
+            let amount = p.unsigned::<u32>()?;
+            p.keyword("from")?;
+            let from = p.unsigned::<u32>()?;
+            p.keyword("to")?;
+            Ok((amount, from, p.unsigned::<u32>()?))
+        })
+        .unwrap();
+
+        assert_eq!((amount, from, to), (3, 1, 2));
+    }
+
+    #[test]
+    fn parses_signed_and_separated() {
+        let numbers = Parser::parse_all("-1,2,+3", |p| p.separated(",", |p| p.signed::<i32>()));
+        assert_eq!(numbers, Ok(vec![-1, 2, 3]));
+
+        let range = Parser::parse_all("2-4", |p| {
+            let start = p.unsigned::<u8>()?;
+            p.literal("-")?;
+            Ok((start, p.unsigned::<u8>()?))
+        });
+        assert_eq!(range, Ok((2, 4)));
+    }
+
+    #[test]
+    fn reports_positions() {
+        let error = Parser::parse_all("move x", |p| {
+            p.keyword("move")?;
+            p.unsigned::<u32>()
+        })
+        .unwrap_err();
+        assert_eq!(error.column, 6);
+        assert_eq!(error.found.as_deref(), Some("x"));
+
+        let error = Parser::parse_all("noop 1", |p| p.one_of(&[("noop", 0)])).unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (6, "end of line"));
+
+        let error = Parser::parse_all("1 X", |p| {
+            p.unsigned::<u8>()?;
+            p.parsed::<crate::geom::Direction>("a direction")
+        })
+        .unwrap_err();
+        assert_eq!((error.column, error.found.as_deref()), (3, Some("X")));
+
+        let error = Parser::parse_all("300", |p| p.unsigned::<u8>()).unwrap_err();
+        assert_eq!(error.expected, "an integer that fits in u8");
+
+        let error = Parser::parse_all("ad", |p| p.one_of(&[("addx", 0), ("noop", 1)]));
+        assert_eq!(error.unwrap_err().expected, "'addx' or 'noop'");
+    }
+}
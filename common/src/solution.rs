@@ -0,0 +1,155 @@
+use std::{any::Any, collections::BTreeMap, fmt::Display, marker::PhantomData};
+
+use crate::{
+    bytes,
+    generate::{Rng, Size},
+    ParseError,
+};
+
+/// A single day's puzzle, split into parsing and the two parts.
+pub trait Solution {
+    const DAY: u8;
+    /// Whether lines of the input are padded with spaces on purpose, so
+    /// trailing whitespace is not a sign of a mangled input.
+    const PADDED_LINES: bool = false;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses the raw bytes of the input, which is how inputs are loaded.
+    /// Checks that they are text and hands them to [`Solution::parse`]
+    /// unless a day overrides it to work on the bytes directly.
+    fn parse_bytes(input: &[u8]) -> Result<Self::Input, ParseError> {
+        Self::parse(bytes::to_str(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// A random but valid input of the given size, for stress tests. Days
+    /// without a generator return `None`.
+    fn generate(_rng: &mut Rng, _size: Size) -> Option<String> {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.number(), f)
+    }
+}
+
+/// Type-erased view of a [`Solution`] so days with different answer types
+/// can live in the same [`Registry`].
+pub trait DynSolution: Send + Sync {
+    fn day(&self) -> u8;
+    fn padded_lines(&self) -> bool;
+    fn parse(&self, input: &[u8]) -> Result<Box<dyn Any>, ParseError>;
+    fn part1(&self, input: &dyn Any) -> String;
+    fn part2(&self, input: &dyn Any) -> String;
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String>;
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S> DynSolution for Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn padded_lines(&self) -> bool {
+        S::PADDED_LINES
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse_bytes(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> String {
+        S::part1(downcast::<S>(input)).to_string()
+    }
+
+    fn part2(&self, input: &dyn Any) -> String {
+        S::part2(downcast::<S>(input)).to_string()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        S::generate(rng, size)
+    }
+}
+
+fn downcast<S>(input: &dyn Any) -> &S::Input
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .unwrap_or_else(|| panic!("Input was not parsed by day {}", S::DAY))
+}
+
+/// Every day that has registered itself, keyed by day number.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u8, Box<dyn DynSolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S>(&mut self)
+    where
+        S: Solution + 'static,
+        S::Input: 'static,
+    {
+        if self
+            .days
+            .insert(S::DAY, Box::new(Erased::<S>(PhantomData)))
+            .is_some()
+        {
+            panic!("Day {} registered twice", S::DAY);
+        }
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn DynSolution> {
+        self.days.get(&day).map(|solution| solution.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynSolution> {
+        self.days.values().map(|solution| solution.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.days.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+}
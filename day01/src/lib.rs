@@ -0,0 +1,308 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::Write,
+    io::{self, Read},
+};
+
+use common::{
+    bytes::{self, LineReader},
+    generate::{Rng, Size},
+    ParseError, Registry, Solution,
+};
+
+pub mod stats;
+
+/// The food items one elf carries, by their calories.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    /// The calories of all items together, or `None` if they do not fit in
+    /// a `u64`.
+    pub fn checked_total(&self) -> Option<u64> {
+        self.items
+            .iter()
+            .try_fold(0u64, |total, &calories| total.checked_add(calories))
+    }
+
+    /// The calories of all items together. Parsing rejects elves whose total
+    /// does not fit, so this only panics for an elf built by hand.
+    pub fn total(&self) -> u64 {
+        self.checked_total()
+            .expect("the calories of an elf do not fit in a u64")
+    }
+}
+
+/// An elf's place in a calorie ranking.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RankedElf {
+    /// Where the elf is in the input, counting from 0.
+    pub index: usize,
+    pub calories: u64,
+}
+
+/// The `k` elves carrying the most calories, most first, with ties going to
+/// the elf that comes first. Only `k` elves are held at a time, so `totals`
+/// can be streamed. There are fewer than `k` if `totals` runs out first.
+pub fn top_elves(totals: impl IntoIterator<Item = u64>, k: usize) -> Vec<RankedElf> {
+    // A min-heap of the best elves so far, whose root is the first to go.
+    let mut best = BinaryHeap::new();
+
+    for (index, calories) in totals.into_iter().enumerate() {
+        let elf = Reverse((calories, Reverse(index)));
+        if best.len() < k {
+            best.push(elf);
+        } else if best.peek().is_some_and(|worst| elf < *worst) {
+            best.pop();
+            best.push(elf);
+        }
+    }
+
+    best.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((calories, Reverse(index)))| RankedElf { index, calories })
+        .collect()
+}
+
+/// Reads elves a line at a time, shared by [`Day01::parse_bytes`] and
+/// [`Totals`] so both accept and reject exactly the same inputs.
+///
+/// Runs of blank lines separate elves, the same way
+/// [`parse_byte_groups`](common::parse_byte_groups) splits groups.
+#[derive(Default)]
+struct ElfParser {
+    line: usize,
+    elves: usize,
+    /// The elf being read and its running total.
+    elf: Option<(Elf, u64)>,
+}
+
+impl ElfParser {
+    /// Takes the next line, returning the elf and total that it finishes.
+    fn line(&mut self, value: &[u8]) -> Result<Option<(Elf, u64)>, ParseError> {
+        self.line += 1;
+        if value.trim_ascii().is_empty() {
+            return Ok(self.elf.take());
+        }
+
+        let error = |expected: &str| {
+            let value = String::from_utf8_lossy(value);
+            ParseError::new(1, expected, Some(&value)).with_line(self.line)
+        };
+
+        let calories = bytes::unsigned::<u64>(value).ok_or_else(|| error("calories"))?;
+        if self.elf.is_none() {
+            self.elves += 1;
+        }
+        let elves = self.elves;
+        let (elf, total) = self
+            .elf
+            .get_or_insert_with(|| (Elf { items: Vec::new() }, 0));
+
+        *total = total.checked_add(calories).ok_or_else(|| {
+            error(&format!(
+                "calories that keep elf {}'s total in a u64",
+                elves
+            ))
+        })?;
+        elf.items.push(calories);
+
+        Ok(None)
+    }
+
+    /// The last elf, when the input does not end with a blank line.
+    fn finish(&mut self) -> Option<(Elf, u64)> {
+        self.elf.take()
+    }
+}
+
+/// The total of each elf, read from `reader` a line at a time so an input
+/// of any size can be ranked with [`top_elves`] without loading it whole.
+///
+/// A malformed line ends up as an [`io::ErrorKind::InvalidData`] error
+/// holding the same [`ParseError`] that [`Day01::parse_bytes`] reports.
+pub struct Totals<R> {
+    lines: LineReader<R>,
+    elves: ElfParser,
+}
+
+impl<R: Read> Totals<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: LineReader::new(reader),
+            elves: ElfParser::default(),
+        }
+    }
+
+    fn next_total(&mut self) -> io::Result<Option<u64>> {
+        while let Some(value) = self.lines.next_line()? {
+            let elf = self
+                .elves
+                .line(value)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            if let Some((_, total)) = elf {
+                return Ok(Some(total));
+            }
+        }
+
+        Ok(self.elves.finish().map(|(_, total)| total))
+    }
+}
+
+impl<R: Read> Iterator for Totals<R> {
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_total().transpose()
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Elf>;
+    type Part1 = u64;
+    // Three totals that each fit in a u64 always fit together in a u128.
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_bytes(input.as_bytes())
+    }
+
+    fn parse_bytes(input: &[u8]) -> Result<Self::Input, ParseError> {
+        let mut parser = ElfParser::default();
+        let mut elves = Vec::new();
+
+        for line in bytes::lines(input) {
+            elves.extend(parser.line(line)?.map(|(elf, _)| elf));
+        }
+        elves.extend(parser.finish().map(|(elf, _)| elf));
+
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> Self::Part1 {
+        top_elves(elves.iter().map(Elf::total), 1)
+            .iter()
+            .map(|elf| elf.calories)
+            .sum()
+    }
+
+    fn part2(elves: &Self::Input) -> Self::Part2 {
+        top_elves(elves.iter().map(Elf::total), 3)
+            .iter()
+            .map(|elf| elf.calories as u128)
+            .sum()
+    }
+
+    /// `n` elves carrying one to eight snacks each.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::new();
+
+        for elf in 0..size.n {
+            if elf > 0 {
+                input.push('\n');
+            }
+
+            for _ in 0..rng.range(1..=8) {
+                writeln!(input, "{}", rng.range(1_000..=60_000)).unwrap();
+            }
+        }
+
+        Some(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day01>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part1(&input), 24000);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part2(&input), 45000);
+    }
+
+    #[test]
+    fn reports_the_elf_whose_total_overflows() {
+        let max = u64::MAX;
+        let error =
+            Day01::parse(&format!("{}\n\n{}\n\n{}\n{}\n", max, max, max - 1, 2)).unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(1, "calories that keep elf 3's total in a u64", Some("2")).with_line(6)
+        );
+
+        let elves = Day01::parse(&format!("{}\n\n{}\n\n{}\n", max, max, max)).unwrap();
+        assert_eq!(Day01::part1(&elves), max);
+        assert_eq!(Day01::part2(&elves), 3 * max as u128);
+        assert_eq!(
+            Elf {
+                items: vec![max, 1]
+            }
+            .checked_total(),
+            None
+        );
+    }
+
+    #[test]
+    fn ranks_the_top_elves() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        let totals = || input.iter().map(Elf::total);
+        let ranked = |index, calories| RankedElf { index, calories };
+
+        assert_eq!(
+            top_elves(totals(), 3),
+            [ranked(3, 24000), ranked(2, 11000), ranked(4, 10000)]
+        );
+        assert_eq!(top_elves(totals(), 0), []);
+        assert_eq!(top_elves(totals(), 100).len(), 5);
+        assert_eq!(
+            top_elves([5, 7, 5, 7], 3),
+            [ranked(1, 7), ranked(3, 7), ranked(0, 5)]
+        );
+    }
+
+    #[test]
+    fn handles_fewer_than_three_elves() {
+        let input = Day01::parse("100\n\n200\n").unwrap();
+
+        assert_eq!(Day01::part1(&input), 200);
+        assert_eq!(Day01::part2(&input), 300);
+        assert_eq!(Day01::part2(&Vec::new()), 0);
+    }
+
+    #[test]
+    fn streams_the_same_totals() {
+        let totals = Totals::new(EXAMPLE.as_bytes())
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        let elves = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(totals, elves.iter().map(Elf::total).collect::<Vec<_>>());
+
+        let input = format!("\n\n{}\n\n{}\n{}\n", u64::MAX, u64::MAX - 1, 2);
+        let error = Totals::new(input.as_bytes())
+            .find_map(Result::err)
+            .and_then(|error| error.into_inner())
+            .and_then(|error| error.downcast::<ParseError>().ok());
+        assert_eq!(error.as_deref(), Day01::parse(&input).err().as_ref());
+    }
+}
@@ -1,41 +1,79 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    path::PathBuf,
-};
+use std::{env, io, process};
+
+use common::{input_source_from_args, parse_input, take_arg, CheckedReader, Solution};
+use day01::{stats::Report, top_elves, Day01, Totals};
+
+const USAGE: &str = "Usage: day01 [--input <path|->] [--report <table|csv>]";
+
+/// How to print the statistics report, if at all.
+enum ReportFormat {
+    Table,
+    Csv,
+}
+
+fn report_arg(args: &mut Vec<String>) -> Result<Option<ReportFormat>, String> {
+    match take_arg(args, "--report")?.as_deref() {
+        None => Ok(None),
+        Some("table") => Ok(Some(ReportFormat::Table)),
+        Some("csv") => Ok(Some(ReportFormat::Csv)),
+        Some(format) => Err(format!("Unknown report format: {}", format)),
+    }
+}
 
 fn main() {
-    let mut project_root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    project_root.push("input.txt");
-    let input = File::open(project_root).expect("Input file not found");
-    let input = BufReader::new(input);
-
-    let mut elves = Vec::new();
-    let mut total_calories = 0;
-
-    for line in input.lines() {
-        let value = line.unwrap();
-        if value.is_empty() {
-            elves.push(total_calories);
-            total_calories = 0;
-            continue;
-        }
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let report = report_arg(&mut args).unwrap_or_else(|error| {
+        eprintln!("{}\n\n{}", error, USAGE);
+        process::exit(2);
+    });
 
-        total_calories += value.parse::<u32>().unwrap();
+    let source = input_source_from_args(env!("CARGO_CRATE_NAME"), args);
+
+    if let Some(format) = report {
+        let input = source.read_bytes().unwrap_or_else(|error| fail(error));
+        let elves = parse_input::<Day01>(&input);
+        let Some(report) = Report::new(&elves, 10) else {
+            eprintln!("There are no elves to report on");
+            process::exit(1);
+        };
+
+        match format {
+            ReportFormat::Table => print!("{}", report),
+            ReportFormat::Csv => print!("{}", report.to_csv()),
+        }
+        return;
     }
 
-    elves.push(total_calories);
+    // Only the ranking is needed here, so the input is streamed rather than
+    // loaded whole, and checked for mangling as it goes by.
+    let reader = source.open().unwrap_or_else(|error| fail(error));
+    let mut reader = CheckedReader::new(reader, Day01::PADDED_LINES);
+    let mut error = None;
+    let totals =
+        Totals::new(&mut reader).map_while(|total| total.map_err(|e| error = Some(e)).ok());
+    let top = top_elves(totals, 3);
 
-    elves.sort_unstable();
-    elves.reverse();
+    for warning in reader.warnings().unwrap_or_else(|error| fail(error)) {
+        eprintln!("Warning: {}", warning);
+    }
+    if let Some(error) = error {
+        fail(error);
+    }
 
-    println!("Max: {}", elves.first().unwrap());
+    println!("Max: {}", top.first().map_or(0, |elf| elf.calories));
 
-    println!("{:?}", &elves[..3]);
+    for (rank, elf) in top.iter().enumerate() {
+        println!("#{}: elf {} with {}", rank + 1, elf.index + 1, elf.calories);
+    }
 
-    let top3 = elves[..3]
-        .iter()
-        .fold(0, |accum, calories| accum + calories);
+    let combined = top.iter().map(|elf| elf.calories as u128).sum::<u128>();
+    println!("Top 3 combined: {}", combined);
+}
 
-    println!("Top 3 combined: {}", top3);
+fn fail(error: io::Error) -> ! {
+    match error.kind() {
+        io::ErrorKind::InvalidData => eprintln!("Invalid input: {}", error),
+        _ => eprintln!("Failed to read input: {}", error),
+    }
+    process::exit(1);
 }
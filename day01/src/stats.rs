@@ -0,0 +1,256 @@
+//! Summary statistics over the elves' food logs, for when the maximum alone
+//! is not enough to plan with.
+
+use std::{
+    cmp::Reverse,
+    fmt::{self, Display, Write},
+};
+
+use crate::Elf;
+
+/// The percentiles of the totals included in a report, besides the median.
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// A range of totals, both ends included, and how many elves fall in it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bucket {
+    pub first: u64,
+    pub last: u64,
+    pub elves: usize,
+}
+
+/// The item with the most calories of all, and the elf carrying it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LargestItem {
+    /// Where the elf is in the input, counting from 0.
+    pub elf: usize,
+    pub calories: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub elves: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// The nearest-rank value of each of [`PERCENTILES`], in that order.
+    pub percentiles: Vec<(u8, u64)>,
+    /// `None` if no elf carries anything.
+    pub largest_item: Option<LargestItem>,
+    pub histogram: Vec<Bucket>,
+}
+
+impl Report {
+    /// Summarises the totals of `elves`, spreading them over at most
+    /// `buckets` equally wide buckets for the histogram. Returns `None` if
+    /// there are no elves.
+    pub fn new(elves: &[Elf], buckets: usize) -> Option<Self> {
+        let mut totals = elves.iter().map(Elf::total).collect::<Vec<_>>();
+        totals.sort_unstable();
+
+        let (&lowest, &highest) = (totals.first()?, totals.last()?);
+        let count = totals.len();
+
+        let middle = count / 2;
+        let median = match count % 2 {
+            0 => (totals[middle - 1] as f64 + totals[middle] as f64) / 2.0,
+            _ => totals[middle] as f64,
+        };
+
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&percentile| {
+                let rank = (percentile as usize * count).div_ceil(100).max(1);
+                (percentile, totals[rank - 1])
+            })
+            .collect();
+
+        let largest_item = elves
+            .iter()
+            .enumerate()
+            .flat_map(|(index, elf)| elf.items.iter().map(move |&calories| (index, calories)))
+            .max_by_key(|&(index, calories)| (calories, Reverse(index)))
+            .map(|(index, calories)| LargestItem {
+                elf: index,
+                calories,
+            });
+
+        Some(Self {
+            elves: count,
+            mean: totals.iter().map(|&total| total as f64).sum::<f64>() / count as f64,
+            median,
+            percentiles,
+            largest_item,
+            histogram: histogram(&totals, lowest, highest, buckets.max(1)),
+        })
+    }
+
+    /// The report as CSV with a `statistic,bucket,value` header, where
+    /// `bucket` is only filled in for the rows of the histogram.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("statistic,bucket,value\n");
+
+        writeln!(csv, "elves,,{}", self.elves).unwrap();
+        writeln!(csv, "mean,,{}", self.mean).unwrap();
+        writeln!(csv, "median,,{}", self.median).unwrap();
+        for (percentile, total) in &self.percentiles {
+            writeln!(csv, "p{},,{}", percentile, total).unwrap();
+        }
+        if let Some(item) = self.largest_item {
+            writeln!(csv, "largest_item,,{}", item.calories).unwrap();
+        }
+        for bucket in &self.histogram {
+            writeln!(
+                csv,
+                "histogram,{}-{},{}",
+                bucket.first, bucket.last, bucket.elves
+            )
+            .unwrap();
+        }
+
+        csv
+    }
+}
+
+/// Counts the sorted `totals` into buckets of equal width from `lowest` to
+/// `highest`. The last bucket ends at `highest`, so it may be narrower.
+fn histogram(totals: &[u64], lowest: u64, highest: u64, buckets: usize) -> Vec<Bucket> {
+    // One less than the width, which would not fit in a u64 for a single
+    // bucket spanning every total.
+    let span = (highest - lowest) / buckets as u64;
+    let mut histogram = Vec::new();
+    let mut first = lowest;
+
+    loop {
+        let last = first.saturating_add(span).min(highest);
+        let elves = totals
+            .iter()
+            .filter(|&&total| (first..=last).contains(&total))
+            .count();
+        histogram.push(Bucket { first, last, elves });
+
+        if last == highest {
+            return histogram;
+        }
+        first = last + 1;
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<14}{:>12}", "Elves", self.elves)?;
+        writeln!(f, "{:<14}{:>12.1}", "Mean", self.mean)?;
+        writeln!(f, "{:<14}{:>12.1}", "Median", self.median)?;
+        for (percentile, total) in &self.percentiles {
+            writeln!(f, "{:<14}{:>12}", format!("P{}", percentile), total)?;
+        }
+        if let Some(item) = self.largest_item {
+            writeln!(
+                f,
+                "{:<14}{:>12} (elf {})",
+                "Largest item",
+                item.calories,
+                item.elf + 1
+            )?;
+        }
+
+        writeln!(f, "\nTotals")?;
+        let most = self.histogram.iter().map(|bucket| bucket.elves).max();
+        for bucket in &self.histogram {
+            // Bars are scaled so the fullest bucket is 40 characters wide.
+            let bar = bucket.elves * 40 / most.unwrap_or(1).max(1);
+            writeln!(
+                f,
+                "{:>12} - {:<12}{:>6} {}",
+                bucket.first,
+                bucket.last,
+                bucket.elves,
+                "#".repeat(bar)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use common::Solution;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn summarises_the_example() {
+        let elves = Day01::parse(EXAMPLE).unwrap();
+        let report = Report::new(&elves, 2).unwrap();
+
+        assert_eq!(elves[0].items, [1000, 2000, 3000]);
+        assert_eq!(report.elves, 5);
+        assert_eq!(report.mean, 11000.0);
+        assert_eq!(report.median, 10000.0);
+        assert_eq!(
+            report.percentiles,
+            [
+                (10, 4000),
+                (25, 6000),
+                (75, 11000),
+                (90, 24000),
+                (99, 24000)
+            ]
+        );
+        assert_eq!(
+            report.largest_item,
+            Some(LargestItem {
+                elf: 4,
+                calories: 10000
+            })
+        );
+        assert_eq!(
+            report.histogram,
+            [
+                Bucket {
+                    first: 4000,
+                    last: 14000,
+                    elves: 4
+                },
+                Bucket {
+                    first: 14001,
+                    last: 24000,
+                    elves: 1
+                }
+            ]
+        );
+        assert!(report
+            .to_csv()
+            .ends_with("largest_item,,10000\nhistogram,4000-14000,4\nhistogram,14001-24000,1\n"));
+    }
+
+    #[test]
+    fn handles_small_inputs() {
+        let elf = |items: &[u64]| Elf {
+            items: items.to_vec(),
+        };
+
+        assert_eq!(Report::new(&[], 10), None);
+
+        let report = Report::new(&[elf(&[]), elf(&[5])], 10).unwrap();
+        assert_eq!(report.median, 2.5);
+        assert_eq!(report.percentiles[0], (10, 0));
+        assert_eq!(report.histogram.len(), 6);
+
+        let report = Report::new(&[elf(&[])], 10).unwrap();
+        assert_eq!(report.largest_item, None);
+        assert_eq!(report.histogram.len(), 1);
+
+        let report = Report::new(&[elf(&[0]), elf(&[u64::MAX])], 1).unwrap();
+        assert_eq!(
+            report.histogram,
+            [Bucket {
+                first: 0,
+                last: u64::MAX,
+                elves: 2
+            }]
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt::Write;
+
+use common::{
+    generate::{Rng, Size},
+    parse::Parser,
+    parse_lines, ParseError, Registry, Solution,
+};
+use rules::{Hand, Outcome, Rules};
+use scoring::{Response, ScoringConfig, Strategy};
+
+pub mod rules;
+pub mod scoring;
+
+#[derive(Clone, Copy)]
+pub struct Turn {
+    player: Hand,
+    opponent: Hand,
+}
+
+impl Turn {
+    /// Reads a round as the opponent's hand followed by the player's.
+    pub fn parse(
+        parser: &mut Parser,
+        opponent: &[(&str, Hand)],
+        player: &[(&str, Hand)],
+    ) -> Result<Self, ParseError> {
+        let opponent = parser.one_of(opponent)?;
+        let player = parser.one_of(player)?;
+
+        Ok(Self { player, opponent })
+    }
+
+    pub fn get_result(&self, rules: &Rules) -> Outcome {
+        rules.outcome(self.player, self.opponent)
+    }
+
+    /// Cannot overflow, as [`ScoringConfig`] rejects scores whose sum for a
+    /// round would not fit in a u32.
+    pub fn get_score(&self, rules: &Rules, scoring: &ScoringConfig) -> u32 {
+        scoring.hand_score(self.player) + scoring.outcome_score(self.get_result(rules))
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Turnv2 {
+    opponent: Hand,
+    result: Outcome,
+}
+
+impl Turnv2 {
+    /// Reads a round as the opponent's hand followed by the outcome the
+    /// player needs.
+    pub fn parse(
+        parser: &mut Parser,
+        opponent: &[(&str, Hand)],
+        result: &[(&str, Outcome)],
+    ) -> Result<Self, ParseError> {
+        let opponent = parser.one_of(opponent)?;
+        let result = parser.one_of(result)?;
+
+        Ok(Self { opponent, result })
+    }
+
+    /// The turn the player has to make for the needed outcome.
+    pub fn to_turn(self, rules: &Rules) -> Turn {
+        Turn {
+            player: rules.hand_for(self.opponent, self.result),
+            opponent: self.opponent,
+        }
+    }
+}
+
+fn choices<T: Copy>(symbols: &[(String, T)]) -> Vec<(&str, T)> {
+    symbols
+        .iter()
+        .map(|(symbol, value)| (symbol.as_str(), *value))
+        .collect()
+}
+
+/// Reads every line of `input` as the turn it stands for under `strategy`.
+pub fn parse_turns(
+    input: &str,
+    rules: &Rules,
+    strategy: &Strategy,
+) -> Result<Vec<Turn>, ParseError> {
+    let opponent = choices(&strategy.opponent);
+
+    match &strategy.response {
+        Response::Player(player) => {
+            let player = choices(player);
+            parse_lines(input, |line| {
+                Parser::parse_all(line, |parser| Turn::parse(parser, &opponent, &player))
+            })
+        }
+        Response::Outcome(result) => {
+            let result = choices(result);
+            parse_lines(input, |line| {
+                Parser::parse_all(line, |parser| {
+                    Ok(Turnv2::parse(parser, &opponent, &result)?.to_turn(rules))
+                })
+            })
+        }
+    }
+}
+
+/// What a strategy guide scores when every turn is played. A u64 holds the
+/// sum of more rounds of u32 scores than fit in memory.
+pub fn total_score(turns: &[Turn], rules: &Rules, scoring: &ScoringConfig) -> u64 {
+    turns
+        .iter()
+        .map(|turn| turn.get_score(rules, scoring) as u64)
+        .sum()
+}
+
+/// A strategy guide read the way each part of the puzzle reads it.
+pub struct Game {
+    pub rules: Rules,
+    pub scoring: ScoringConfig,
+    turns: Vec<Turn>,
+    correct_turns: Vec<Turn>,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Game;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rules = Rules::rock_paper_scissors();
+        let scoring = ScoringConfig::rock_paper_scissors();
+        let read = |name| {
+            let strategy = scoring
+                .strategy(name)
+                .expect("the puzzle has both strategies");
+            parse_turns(input, &rules, strategy)
+        };
+
+        Ok(Game {
+            turns: read("part1")?,
+            correct_turns: read("part2")?,
+            rules,
+            scoring,
+        })
+    }
+
+    fn part1(game: &Self::Input) -> Self::Part1 {
+        total_score(&game.turns, &game.rules, &game.scoring)
+    }
+
+    fn part2(game: &Self::Input) -> Self::Part2 {
+        total_score(&game.correct_turns, &game.rules, &game.scoring)
+    }
+
+    /// `n` rounds of random hands.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::new();
+
+        for _ in 0..size.n {
+            let opponent = rng.choose(&['A', 'B', 'C']);
+            let column = rng.choose(&['X', 'Y', 'Z']);
+            writeln!(input, "{} {}", opponent, column).unwrap();
+        }
+
+        Some(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day02>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part1(&input), 15);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part2(&input), 12);
+    }
+
+    #[test]
+    fn totals_scores_beyond_u32() {
+        let rules = Rules::rock_paper_scissors();
+        let scoring = ScoringConfig::from_toml(
+            "[hands]\nrock = 4000000000\npaper = 2\nscissors = 3\n\
+             [outcomes]\nlose = 0\ndraw = 3\nwin = 6\n\
+             [strategies.rocks]\nopponent = { A = \"scissors\" }\nplayer = { X = \"rock\" }\n",
+            &rules,
+        )
+        .unwrap();
+        let turns = parse_turns("A X\nA X\n", &rules, scoring.strategy("rocks").unwrap()).unwrap();
+
+        assert_eq!(total_score(&turns, &rules, &scoring), 8_000_000_012);
+    }
+}
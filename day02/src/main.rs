@@ -1,166 +1,68 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    path::PathBuf,
-};
-
-#[derive(Clone, Copy)]
-enum Hand {
-    Rock = 1,
-    Paper = 2,
-    Scissors = 3,
-}
-
-impl From<&str> for Hand {
-    fn from(input: &str) -> Self {
-        match input {
-            "A" | "X" => Self::Rock,
-            "B" | "Y" => Self::Paper,
-            "C" | "Z" => Self::Scissors,
-            _ => panic!("Unknown symbol {}", input),
-        }
-    }
-}
-
-impl Hand {
-    fn get_winning_hand(hand: &Hand) -> Hand {
-        match hand {
-            Hand::Rock => Hand::Paper,
-            Hand::Paper => Hand::Scissors,
-            Hand::Scissors => Hand::Rock,
-        }
-    }
-
-    fn get_losing_hand(hand: &Hand) -> Hand {
-        match hand {
-            Hand::Rock => Hand::Scissors,
-            Hand::Paper => Hand::Rock,
-            Hand::Scissors => Hand::Paper,
-        }
-    }
+use std::{env, fs, path::Path, process};
 
-    fn get_drawing_hand(hand: &Hand) -> Hand {
-        hand.clone()
-    }
-}
-
-struct Turn {
-    player: Hand,
-    opponent: Hand,
-}
-
-impl From<&str> for Turn {
-    fn from(input: &str) -> Self {
-        let mut parts = input.split_whitespace();
-
-        Self {
-            opponent: parts.next().unwrap().into(),
-            player: parts.next().unwrap().into(),
-        }
-    }
-}
-
-impl From<Turnv2> for Turn {
-    fn from(turn: Turnv2) -> Self {
-        Self {
-            player: match turn.result {
-                Result::Win => Hand::get_winning_hand(&turn.opponent),
-                Result::Lose => Hand::get_losing_hand(&turn.opponent),
-                Result::Draw => Hand::get_drawing_hand(&turn.opponent),
-            },
-            opponent: turn.opponent,
-        }
-    }
-}
+use common::{
+    bytes, load_input_from_args, parse_input, take_arg, InputWarning, ParseError, Solution,
+};
+use day02::{parse_turns, rules::Rules, scoring::ScoringConfig, total_score, Day02};
 
-impl Turn {
-    fn get_result(&self) -> Result {
-        self.into()
-    }
+const USAGE: &str =
+    "Usage: day02 [--input <path|->] [--rules <path>] [--scoring <path.toml|path.json>]";
 
-    fn get_score(&self) -> u32 {
-        let result = self.get_result();
-        self.player as u32 + result as u32
-    }
+fn exit_with(error: String) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
 }
 
-enum Result {
-    Win = 6,
-    Lose = 0,
-    Draw = 3,
-}
+fn read_rules(path: &str) -> Rules {
+    let rules = fs::read_to_string(path).unwrap_or_else(|error| {
+        exit_with(format!("Failed to read rules: {}: {}", path, error));
+    });
 
-impl From<&str> for Result {
-    fn from(result: &str) -> Self {
-        match result {
-            "X" => Self::Lose,
-            "Y" => Self::Draw,
-            "Z" => Self::Win,
-            _ => panic!("Unknown symbol {}", result),
-        }
-    }
+    rules.parse().unwrap_or_else(|error: ParseError| {
+        exit_with(format!("Invalid rules: {}: {}", path, error));
+    })
 }
 
-impl From<&Turn> for Result {
-    fn from(turn: &Turn) -> Self {
-        match turn.player {
-            Hand::Paper => match turn.opponent {
-                Hand::Paper => Result::Draw,
-                Hand::Rock => Result::Win,
-                Hand::Scissors => Result::Lose,
-            },
-            Hand::Rock => match turn.opponent {
-                Hand::Paper => Result::Lose,
-                Hand::Rock => Result::Draw,
-                Hand::Scissors => Result::Win,
-            },
-            Hand::Scissors => match turn.opponent {
-                Hand::Paper => Result::Win,
-                Hand::Rock => Result::Lose,
-                Hand::Scissors => Result::Draw,
-            },
-        }
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let (rules, scoring) = take_arg(&mut args, "--rules")
+        .and_then(|rules| Ok((rules, take_arg(&mut args, "--scoring")?)))
+        .and_then(|(rules, scoring)| match (rules, scoring) {
+            (Some(_), None) => Err("--rules needs --scoring to go with it".to_string()),
+            paths => Ok(paths),
+        })
+        .unwrap_or_else(|error| {
+            eprintln!("{}\n\n{}", error, USAGE);
+            process::exit(2);
+        });
+
+    let input = load_input_from_args(env!("CARGO_CRATE_NAME"), args);
+
+    let Some(scoring) = scoring else {
+        let game = parse_input::<Day02>(&input);
+
+        println!("Initial score: {}", Day02::part1(&game));
+
+        println!("Correct score: {}", Day02::part2(&game));
+        return;
+    };
+
+    let rules = rules.map_or_else(Rules::rock_paper_scissors, |path| read_rules(&path));
+    let scoring = ScoringConfig::load(Path::new(&scoring), &rules)
+        .unwrap_or_else(|error| exit_with(format!("Invalid scoring: {}", error)));
+
+    for warning in InputWarning::check(&input, false) {
+        eprintln!("Warning: {}", warning);
     }
-}
-
-struct Turnv2 {
-    opponent: Hand,
-    result: Result,
-}
-
-impl From<&str> for Turnv2 {
-    fn from(input: &str) -> Self {
-        let mut parts = input.split_whitespace();
-
-        Self {
-            opponent: parts.next().unwrap().into(),
-            result: parts.next().unwrap().into(),
+    let input = bytes::to_str(&input)
+        .unwrap_or_else(|error| exit_with(format!("Invalid input: {}", error)));
+
+    // Strategies may read the guide with different symbols, so each one may
+    // fail on its own.
+    for (name, strategy) in scoring.strategies() {
+        match parse_turns(input, &rules, strategy) {
+            Ok(turns) => println!("{}: {}", name, total_score(&turns, &rules, &scoring)),
+            Err(error) => println!("{}: cannot read the guide: {}", name, error),
         }
     }
 }
-
-fn main() {
-    let mut project_root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    project_root.push("input.txt");
-    let input = File::open(project_root).expect("Input file not found");
-    let input = BufReader::new(input);
-
-    let mut turns = Vec::new();
-    let mut correct_turns = Vec::new();
-
-    for line in input.lines().map(|l| l.unwrap()) {
-        let turn: Turn = <String as AsRef<str>>::as_ref(&line).into();
-        turns.push(turn);
-
-        let correct_turn: Turnv2 = <String as AsRef<str>>::as_ref(&line).into();
-        correct_turns.push(Turn::from(correct_turn));
-    }
-
-    let score: u32 = turns.iter().map(|turn| turn.get_score()).sum();
-
-    println!("Initial score: {}", score);
-
-    let correct_score: u32 = correct_turns.iter().map(|turn| turn.get_score()).sum();
-
-    println!("Correct score: {}", correct_score);
-}
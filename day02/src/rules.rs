@@ -0,0 +1,275 @@
+//! The rules of Rock Paper Scissors and its variants, read from a small text
+//! format so a new variant needs no code:
+//!
+//! ```text
+//! # Comments and blank lines are ignored.
+//! hand <name>
+//! <name> beats <name>...
+//! cyclic
+//! ```
+//!
+//! `cyclic` makes every hand beat the `(N - 1) / 2` hands listed before it,
+//! wrapping around, which needs an odd number of hands. Without it, who beats
+//! whom comes from the `beats` lines. Every hand has to beat at least one hand
+//! and lose to at least one, so any outcome can be played against any hand.
+//!
+//! How hands are written in a strategy guide and what they score is up to a
+//! [`ScoringConfig`](crate::scoring::ScoringConfig).
+
+use std::str::FromStr;
+
+use common::{parse::Parser, ParseError};
+use serde::Deserialize;
+
+const ROCK_PAPER_SCISSORS: &str = include_str!("../rules/rock-paper-scissors.txt");
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Self; 3] = [Self::Lose, Self::Draw, Self::Win];
+}
+
+/// A hand of a game, standing for its place in [`Rules::hands`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hand(usize);
+
+impl Hand {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    hands: Vec<String>,
+    /// `beats[a][b]` is whether hand `a` beats hand `b`.
+    beats: Vec<Vec<bool>>,
+}
+
+impl Rules {
+    /// The rules of the puzzle.
+    pub fn rock_paper_scissors() -> Self {
+        ROCK_PAPER_SCISSORS
+            .parse()
+            .expect("the bundled rules are valid")
+    }
+
+    /// The names of the hands, in the order they are listed.
+    pub fn hands(&self) -> &[String] {
+        &self.hands
+    }
+
+    pub fn name(&self, hand: Hand) -> &str {
+        &self.hands[hand.0]
+    }
+
+    pub fn hand_named(&self, name: &str) -> Option<Hand> {
+        self.hands.iter().position(|hand| hand == name).map(Hand)
+    }
+
+    fn all_hands(&self) -> impl Iterator<Item = Hand> {
+        (0..self.hands.len()).map(Hand)
+    }
+
+    pub fn outcome(&self, player: Hand, opponent: Hand) -> Outcome {
+        if self.beats[player.0][opponent.0] {
+            Outcome::Win
+        } else if self.beats[opponent.0][player.0] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The first hand, in the order they are listed, that gets `outcome`
+    /// against `opponent`.
+    pub fn hand_for(&self, opponent: Hand, outcome: Outcome) -> Hand {
+        self.all_hands()
+            .find(|&hand| self.outcome(hand, opponent) == outcome)
+            .expect("validated rules have every outcome against every hand")
+    }
+}
+
+impl FromStr for Rules {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut hands = Vec::<String>::new();
+        let mut beats = Vec::<(usize, usize)>::new();
+        let mut cyclic = None;
+
+        for (i, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            Parser::parse_all(line, |parser| {
+                match parser.clone().word("a rule")? {
+                    "hand" => {
+                        parser.keyword("hand")?;
+                        let start = parser.clone();
+                        let name = parser.word("the name of the hand")?;
+                        if hands.iter().any(|hand| hand == name) {
+                            return Err(start.error("a name not used by another hand"));
+                        }
+                        hands.push(name.to_string());
+                    }
+                    "cyclic" => {
+                        parser.keyword("cyclic")?;
+                        cyclic = Some(i + 1);
+                    }
+                    _ => {
+                        let names = hand_names(&hands);
+                        let winner = parser.one_of(&names)?;
+                        parser.keyword("beats")?;
+
+                        loop {
+                            let start = parser.clone();
+                            let loser = parser.one_of(&names)?;
+                            if loser == winner || beats.contains(&(loser, winner)) {
+                                let expected =
+                                    format!("a hand that does not beat {}", hands[winner]);
+                                return Err(start.error(expected));
+                            }
+                            beats.push((winner, loser));
+
+                            if parser.is_empty() {
+                                break;
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            })
+            .map_err(|error| error.with_line(i + 1))?;
+        }
+
+        let end = |expected: String| {
+            Err(ParseError::new(1, expected, None).with_line(source.lines().count() + 1))
+        };
+
+        if hands.len() < 3 {
+            return end("at least three hands".to_string());
+        }
+
+        let count = hands.len();
+        if let Some(line) = cyclic {
+            let error =
+                |expected: &str| Err(ParseError::new(1, expected, Some("cyclic")).with_line(line));
+            if !beats.is_empty() {
+                return error("either cyclic or beats lines, not both");
+            }
+            if count.is_multiple_of(2) {
+                return error("an odd number of hands for cyclic");
+            }
+
+            for winner in 0..count {
+                for distance in 1..=count / 2 {
+                    beats.push((winner, (winner + count - distance) % count));
+                }
+            }
+        }
+
+        let mut table = vec![vec![false; count]; count];
+        for (winner, loser) in beats {
+            table[winner][loser] = true;
+        }
+
+        for (i, hand) in hands.iter().enumerate() {
+            if !table[i].contains(&true) {
+                return end(format!("a hand that {} beats", hand));
+            }
+            if !table.iter().any(|row| row[i]) {
+                return end(format!("a hand that beats {}", hand));
+            }
+        }
+
+        Ok(Self {
+            hands,
+            beats: table,
+        })
+    }
+}
+
+fn hand_names(hands: &[String]) -> Vec<(&str, usize)> {
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| (hand.as_str(), i))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIZARD_SPOCK: &str = include_str!("../rules/rock-paper-scissors-lizard-spock.txt");
+
+    fn hand(rules: &Rules, name: &str) -> Hand {
+        rules.hand_named(name).unwrap()
+    }
+
+    #[test]
+    fn plays_rock_paper_scissors() {
+        let rules = Rules::rock_paper_scissors();
+        let [rock, paper, scissors] = ["rock", "paper", "scissors"].map(|name| hand(&rules, name));
+
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, paper), Outcome::Lose);
+        assert_eq!(rules.outcome(scissors, scissors), Outcome::Draw);
+        assert_eq!(rules.hand_for(scissors, Outcome::Win), rock);
+    }
+
+    #[test]
+    fn lizard_spock_is_a_cyclic_tournament() {
+        let explicit = LIZARD_SPOCK.parse::<Rules>().unwrap();
+        let cyclic = explicit
+            .hands()
+            .iter()
+            .map(|hand| format!("hand {}\n", hand))
+            .collect::<String>()
+            + "cyclic\n";
+        let cyclic = cyclic.parse::<Rules>().unwrap();
+
+        // The bundled file lists the hands in the order where each beats the
+        // two before it, so both must agree on every game.
+        assert_eq!(explicit, cyclic);
+
+        let [spock, lizard] = ["spock", "lizard"].map(|name| hand(&explicit, name));
+        assert_eq!(explicit.outcome(lizard, spock), Outcome::Win);
+    }
+
+    #[test]
+    fn rejects_incomplete_rules() {
+        let hands = "hand rock\nhand paper\nhand scissors\n";
+        let error = |rules: String| rules.parse::<Rules>().unwrap_err();
+
+        assert_eq!(
+            error("hand rock\nhand paper\ncyclic\n".to_string()).expected,
+            "at least three hands"
+        );
+        assert_eq!(
+            error(format!("{}rock beats scissors\n", hands)).expected,
+            "a hand that beats rock"
+        );
+        assert_eq!(
+            error(format!("{}rock beats paper\npaper beats rock\n", hands)).expected,
+            "a hand that does not beat paper"
+        );
+        assert_eq!(
+            error(format!("{}hand rock\n", hands)),
+            ParseError::new(6, "a name not used by another hand", Some("rock")).with_line(4)
+        );
+        assert_eq!(
+            error(format!("{}hand lizard\ncyclic\n", hands)).expected,
+            "an odd number of hands for cyclic"
+        );
+    }
+}
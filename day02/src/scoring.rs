@@ -0,0 +1,296 @@
+//! How a strategy guide is read and scored, loaded from TOML or JSON so other
+//! readings of a guide can be tried without recompiling:
+//!
+//! ```toml
+//! [hands]            # what playing each hand of the rules scores
+//! rock = 1
+//!
+//! [outcomes]         # what each outcome scores
+//! lose = 0
+//! draw = 3
+//! win = 6
+//!
+//! [strategies.part1] # any number of named ways to read the guide
+//! opponent = { A = "rock" }
+//! player = { X = "rock" }   # or: outcome = { X = "lose" }
+//! ```
+//!
+//! A strategy reads the first column of the guide as the opponent's hand, and
+//! the second either as the player's hand or as the outcome the player needs.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::rules::{Hand, Outcome, Rules};
+
+const ROCK_PAPER_SCISSORS: &str = include_str!("../scoring/rock-paper-scissors.toml");
+
+/// What the second column of a guide stands for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Response {
+    Player(Vec<(String, Hand)>),
+    Outcome(Vec<(String, Outcome)>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Strategy {
+    pub opponent: Vec<(String, Hand)>,
+    pub response: Response,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScoringConfig {
+    /// Indexed by [`Hand::index`].
+    hand_scores: Vec<u32>,
+    /// In the order of [`Outcome::ALL`].
+    outcome_scores: [u32; 3],
+    strategies: BTreeMap<String, Strategy>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawConfig {
+    hands: BTreeMap<String, u32>,
+    outcomes: RawOutcomes,
+    strategies: BTreeMap<String, RawStrategy>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawOutcomes {
+    lose: u32,
+    draw: u32,
+    win: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawStrategy {
+    opponent: BTreeMap<String, String>,
+    player: Option<BTreeMap<String, String>>,
+    outcome: Option<BTreeMap<String, Outcome>>,
+}
+
+impl ScoringConfig {
+    /// How the puzzle reads and scores its guide, under
+    /// [`Rules::rock_paper_scissors`]. Its strategies are `part1` and
+    /// `part2`.
+    pub fn rock_paper_scissors() -> Self {
+        Self::from_toml(ROCK_PAPER_SCISSORS, &Rules::rock_paper_scissors())
+            .expect("the bundled scoring is valid")
+    }
+
+    pub fn from_toml(source: &str, rules: &Rules) -> Result<Self, String> {
+        let raw = toml::from_str(source).map_err(|error| error.to_string())?;
+        Self::resolve(raw, rules)
+    }
+
+    pub fn from_json(source: &str, rules: &Rules) -> Result<Self, String> {
+        let raw = serde_json::from_str(source).map_err(|error| error.to_string())?;
+        Self::resolve(raw, rules)
+    }
+
+    /// Reads a config as TOML or JSON depending on the extension of `path`.
+    pub fn load(path: &Path, rules: &Rules) -> Result<Self, String> {
+        let source =
+            fs::read_to_string(path).map_err(|error| format!("{}: {}", path.display(), error))?;
+
+        let config = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml(&source, rules),
+            Some("json") => Self::from_json(&source, rules),
+            _ => Err("expected a .toml or .json file".to_string()),
+        };
+        config.map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    /// Checks `raw` against `rules`, turning the names of hands into hands.
+    fn resolve(raw: RawConfig, rules: &Rules) -> Result<Self, String> {
+        let hand = |name: &str| {
+            rules
+                .hand_named(name)
+                .ok_or_else(|| format!("unknown hand '{}'", name))
+        };
+
+        for name in raw.hands.keys() {
+            hand(name)?;
+        }
+        let hand_scores = rules
+            .hands()
+            .iter()
+            .map(|name| {
+                raw.hands
+                    .get(name)
+                    .copied()
+                    .ok_or_else(|| format!("no score for hand '{}'", name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let outcome_scores = [raw.outcomes.lose, raw.outcomes.draw, raw.outcomes.win];
+        let highest = |scores: &[u32]| scores.iter().copied().max().unwrap_or(0);
+        if highest(&hand_scores)
+            .checked_add(highest(&outcome_scores))
+            .is_none()
+        {
+            return Err("the score of a round does not fit in a u32".to_string());
+        }
+
+        if raw.strategies.is_empty() {
+            return Err("no strategies".to_string());
+        }
+
+        let mut strategies = BTreeMap::new();
+        for (name, strategy) in raw.strategies {
+            let in_strategy = |error: String| format!("strategy '{}': {}", name, error);
+            let symbols = |column: &str, map: BTreeMap<String, String>| {
+                check_symbols(column, map.keys())?;
+                map.into_iter()
+                    .map(|(symbol, name)| Ok((symbol, hand(&name)?)))
+                    .collect::<Result<Vec<_>, String>>()
+            };
+
+            let opponent = symbols("opponent", strategy.opponent).map_err(in_strategy)?;
+            let response = match (strategy.player, strategy.outcome) {
+                (Some(player), None) => {
+                    Response::Player(symbols("player", player).map_err(in_strategy)?)
+                }
+                (None, Some(outcome)) => {
+                    check_symbols("outcome", outcome.keys()).map_err(in_strategy)?;
+                    Response::Outcome(outcome.into_iter().collect())
+                }
+                _ => {
+                    return Err(in_strategy(
+                        "needs either player or outcome symbols".to_string(),
+                    ))
+                }
+            };
+
+            strategies.insert(name, Strategy { opponent, response });
+        }
+
+        Ok(Self {
+            hand_scores,
+            outcome_scores,
+            strategies,
+        })
+    }
+
+    pub fn strategy(&self, name: &str) -> Option<&Strategy> {
+        self.strategies.get(name)
+    }
+
+    /// Every strategy, ordered by name.
+    pub fn strategies(&self) -> impl Iterator<Item = (&str, &Strategy)> {
+        self.strategies
+            .iter()
+            .map(|(name, strategy)| (name.as_str(), strategy))
+    }
+
+    pub fn hand_score(&self, hand: Hand) -> u32 {
+        self.hand_scores[hand.index()]
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        self.outcome_scores[outcome as usize]
+    }
+}
+
+/// Symbols are read as words, so they cannot be empty or contain whitespace.
+fn check_symbols<'a>(
+    column: &str,
+    symbols: impl Iterator<Item = &'a String>,
+) -> Result<(), String> {
+    let mut symbols = symbols.peekable();
+    if symbols.peek().is_none() {
+        return Err(format!("no {} symbols", column));
+    }
+
+    match symbols.find(|symbol| symbol.is_empty() || symbol.contains(char::is_whitespace)) {
+        Some(symbol) => Err(format!(
+            "{} symbol {:?} is not a single word",
+            column, symbol
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIZARD_SPOCK_RULES: &str = include_str!("../rules/rock-paper-scissors-lizard-spock.txt");
+    const LIZARD_SPOCK: &str = include_str!("../scoring/rock-paper-scissors-lizard-spock.json");
+
+    #[test]
+    fn loads_the_bundled_configs() {
+        let scoring = ScoringConfig::rock_paper_scissors();
+        assert_eq!(
+            scoring
+                .strategies()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>(),
+            ["part1", "part2"]
+        );
+
+        let rules = LIZARD_SPOCK_RULES.parse::<Rules>().unwrap();
+        let scoring = ScoringConfig::from_json(LIZARD_SPOCK, &rules).unwrap();
+        let spock = rules.hand_named("spock").unwrap();
+        assert_eq!(scoring.hand_score(spock), 5);
+        assert_eq!(scoring.outcome_score(Outcome::Win), 6);
+        assert!(matches!(
+            scoring.strategy("outcomes").unwrap().response,
+            Response::Outcome(_)
+        ));
+    }
+
+    #[test]
+    fn rejects_configs_that_do_not_fit_the_rules() {
+        let rules = Rules::rock_paper_scissors();
+        let config = |hands: &str, strategy: &str| {
+            let source = format!(
+                "[hands]\n{}\n[outcomes]\nlose = 0\ndraw = 3\nwin = 6\n[strategies.guess]\n{}\n",
+                hands, strategy
+            );
+            ScoringConfig::from_toml(&source, &rules).unwrap_err()
+        };
+        let hands = "rock = 1\npaper = 2\nscissors = 3";
+
+        assert_eq!(
+            config(
+                "rock = 1\npaper = 2",
+                "opponent = { A = \"rock\" }\nplayer = { X = \"rock\" }"
+            ),
+            "no score for hand 'scissors'"
+        );
+        assert_eq!(
+            config(
+                hands,
+                "opponent = { A = \"rock\" }\nplayer = { X = \"lizard\" }"
+            ),
+            "strategy 'guess': unknown hand 'lizard'"
+        );
+        assert_eq!(
+            config(hands, "opponent = { A = \"rock\" }"),
+            "strategy 'guess': needs either player or outcome symbols"
+        );
+        assert_eq!(
+            config(
+                hands,
+                "opponent = { \"A B\" = \"rock\" }\noutcome = { X = \"win\" }"
+            ),
+            "strategy 'guess': opponent symbol \"A B\" is not a single word"
+        );
+        assert_eq!(
+            config(
+                "rock = 4294967290\npaper = 2\nscissors = 3",
+                "opponent = { A = \"rock\" }\nplayer = { X = \"rock\" }"
+            ),
+            "the score of a round does not fit in a u32"
+        );
+        assert!(config(
+            hands,
+            "opponent = { A = \"rock\" }\noutcome = { X = \"tie\" }"
+        )
+        .contains("unknown variant"));
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    generate::{Rng, Size},
+    parse::Parser,
+    parse_lines, ParseError, Registry, Solution,
+};
+
+fn char_to_priority(char: &char) -> u32 {
+    let point = *char as u32;
+    if point <= 90 {
+        point - 65 + 27
+    } else {
+        point - 97 + 1
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let mut parser = Parser::new(line);
+            let items = parser.take_while(|char| char.is_ascii_alphabetic());
+
+            match parser.rest().chars().next() {
+                Some(char) => Err(ParseError::at(
+                    line,
+                    &parser.rest()[..char.len_utf8()],
+                    "an item between a-z or A-Z",
+                )),
+                None => Ok(items.to_string()),
+            }
+        })
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Self::Part1 {
+        let mut sum = 0;
+
+        for line in rucksacks {
+            let half_point = line.len() / 2;
+            let first_half = &line[..half_point];
+            let second_half = &line[half_point..];
+
+            let first_characters: HashSet<char> = HashSet::from_iter(first_half.chars());
+            let second_characters: HashSet<char> = HashSet::from_iter(second_half.chars());
+
+            let common_characters = first_characters.intersection(&second_characters);
+
+            sum += common_characters.map(char_to_priority).sum::<u32>()
+        }
+
+        sum
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Self::Part2 {
+        let rucksack_sets: Vec<HashSet<char>> = rucksacks
+            .iter()
+            .map(|line| HashSet::from_iter(line.chars()))
+            .collect();
+
+        rucksack_sets
+            .chunks(3)
+            .flat_map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(HashMap::new(), |mut accum, set| {
+                        set.iter().for_each(|char| {
+                            match accum.get(char) {
+                                Some(count) => accum.insert(char, count + 1),
+                                None => accum.insert(char, 1),
+                            };
+                        });
+
+                        accum
+                    })
+                    .iter()
+                    .filter(|&(_char, count)| *count == 3)
+                    .map(|(char, _count)| *char)
+                    .collect::<Vec<&char>>()
+            })
+            .map(char_to_priority)
+            .sum::<u32>()
+    }
+
+    /// `n` rucksacks, rounded up to whole groups of three.
+    ///
+    /// Each group splits the letters between its badge and three disjoint
+    /// pools, one per elf. An elf packs its shared item into both halves, the
+    /// badge into the first half and the rest of each half from separate
+    /// parts of its pool, so both puzzle rules hold by construction.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let mut letters = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+        let mut input = String::new();
+
+        for _ in 0..size.n.div_ceil(3) {
+            rng.shuffle(&mut letters);
+            let (badge, pools) = letters.split_first().unwrap();
+
+            for pool in pools.chunks(17) {
+                let (shared, rest) = pool.split_first().unwrap();
+                let (first_items, second_items) = rest.split_at(8);
+                let half = rng.range(3..=12) as usize;
+
+                let mut first = vec![*shared, *badge];
+                first.extend((2..half).map(|_| *rng.choose(first_items)));
+                let mut second = vec![*shared];
+                second.extend((1..half).map(|_| *rng.choose(second_items)));
+
+                rng.shuffle(&mut first);
+                rng.shuffle(&mut second);
+                input.extend(first.into_iter().chain(second));
+                input.push('\n');
+            }
+        }
+
+        Some(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day03>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part1(&input), 157);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part2(&input), 70);
+    }
+}
@@ -0,0 +1,148 @@
+use std::{collections::HashSet, fmt::Write, str::FromStr};
+
+use common::{
+    generate::{Rng, Size},
+    parse::Parser,
+    parse_lines, ParseError, Registry, Solution,
+};
+
+pub struct Section {
+    set: HashSet<u8>,
+}
+
+impl Section {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let start = parser.unsigned::<u8>()?;
+        parser.literal("-")?;
+        let end = parser.unsigned::<u8>()?;
+
+        Ok(Self {
+            set: HashSet::from_iter(start..=end),
+        })
+    }
+}
+
+impl FromStr for Section {
+    type Err = ParseError;
+
+    fn from_str(range: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(range, Section::parse)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(Section, Section)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            Parser::parse_all(line, |parser| {
+                let first = Section::parse(parser)?;
+                parser.literal(",")?;
+                Ok((first, Section::parse(parser)?))
+            })
+        })
+    }
+
+    fn part1(pairs: &Self::Input) -> Self::Part1 {
+        pairs
+            .iter()
+            .filter(|(first, second)| {
+                first.set.is_subset(&second.set) || first.set.is_superset(&second.set)
+            })
+            .count()
+    }
+
+    fn part2(pairs: &Self::Input) -> Self::Part2 {
+        pairs
+            .iter()
+            .filter(|(first, second)| first.set.intersection(&second.set).count() > 0)
+            .count()
+    }
+
+    /// `n` pairs of section ranges between 1 and 99.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::new();
+
+        for _ in 0..size.n {
+            let mut range = || {
+                let start = rng.range(1..=99);
+                (start, rng.range(start..=99))
+            };
+            let (first, second) = (range(), range());
+            writeln!(input, "{}-{},{}-{}", first.0, first.1, second.0, second.1).unwrap();
+        }
+
+        Some(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day04>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part1(&input), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part2(&input), 4);
+    }
+
+    /// Counts the fully and the partially overlapping pairs by comparing the
+    /// ends of the ranges as written.
+    fn naive_overlaps(input: &str) -> (usize, usize) {
+        let (mut full, mut partial) = (0, 0);
+
+        for line in input.lines() {
+            let ends = line
+                .split([',', '-'])
+                .map(|number| number.parse::<u32>().unwrap())
+                .collect::<Vec<_>>();
+            let [a, b, c, d] = ends[..] else {
+                panic!("not a pair of ranges: {}", line);
+            };
+
+            if (a <= c && d <= b) || (c <= a && b <= d) {
+                full += 1;
+            }
+            if a <= d && c <= b {
+                partial += 1;
+            }
+        }
+
+        (full, partial)
+    }
+
+    #[test]
+    fn matches_naive_overlaps_on_generated_inputs() {
+        for seed in 0..200 {
+            let mut rng = Rng::new(seed);
+            let n = rng.below(50) + 1;
+            let input = Day04::generate(&mut rng, Size { n, m: n }).unwrap();
+            let pairs = Day04::parse(&input).unwrap();
+
+            assert_eq!(
+                (Day04::part1(&pairs), Day04::part2(&pairs)),
+                naive_overlaps(&input),
+                "{}",
+                input
+            );
+        }
+    }
+}
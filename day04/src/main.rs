@@ -1,45 +1,10 @@
-use std::collections::HashSet;
-
-use common::{read_input, read_lines};
-
-struct Section {
-    set: HashSet<u8>,
-}
-
-impl From<&str> for Section {
-    fn from(range: &str) -> Self {
-        let mut split = range.split('-');
-
-        let start = split.next().unwrap().parse::<u8>().unwrap();
-        let end = split.next().unwrap().parse::<u8>().unwrap();
-
-        Self {
-            set: HashSet::from_iter(start..=end),
-        }
-    }
-}
+use common::{load_input, parse_input, Solution};
+use day04::Day04;
 
 fn main() {
-    let reader = read_input(env!("CARGO_CRATE_NAME"));
-    let lines = read_lines(reader);
-
-    let mut fully_overlaps = 0;
-    let mut overlaps = 0;
-
-    for line in lines {
-        let mut sections = line.split(',');
-        let first = Section::from(sections.next().unwrap());
-        let second = Section::from(sections.next().unwrap());
-
-        if first.set.is_subset(&second.set) || first.set.is_superset(&second.set) {
-            fully_overlaps += 1;
-        }
-
-        if first.set.intersection(&second.set).count() > 0 {
-            overlaps += 1;
-        }
-    }
+    let input = load_input(env!("CARGO_CRATE_NAME"));
+    let pairs = parse_input::<Day04>(&input);
 
-    println!("Fully overlaps: {}", fully_overlaps);
-    println!("Partially overlaps: {}", overlaps);
+    println!("Fully overlaps: {}", Day04::part1(&pairs));
+    println!("Partially overlaps: {}", Day04::part2(&pairs));
 }
@@ -0,0 +1,332 @@
+use std::{fmt::Write, str::FromStr};
+
+use common::{
+    generate::{Rng, Size},
+    parse::Parser,
+    parse_groups, ParseError, Registry, Solution,
+};
+
+pub struct Move {
+    from: usize,
+    to: usize,
+    amount: usize,
+}
+
+/// A number that counts from one, such as a crate amount or a stack number.
+fn positive(parser: &mut Parser, expected: &str) -> Result<usize, ParseError> {
+    let start = parser.clone();
+    match parser.unsigned::<usize>() {
+        Ok(0) | Err(_) => Err(start.error(expected)),
+        Ok(number) => Ok(number),
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(line, |parser| {
+            parser.keyword("move")?;
+            let amount = positive(parser, "a positive crate amount")?;
+            parser.keyword("from")?;
+            let from = positive(parser, "a stack number")?;
+            parser.keyword("to")?;
+            let to = positive(parser, "a stack number")?;
+
+            Ok(Self {
+                amount,
+                from: from - 1,
+                to: to - 1,
+            })
+        })
+    }
+}
+
+/// Reads the crate drawing, which ends with a header numbering the stacks
+/// from 1, such as ` 1   2   3 `.
+fn initialize_stacks(drawing: &[&str]) -> Result<Vec<Vec<char>>, ParseError> {
+    let (header, rows) = drawing
+        .split_last()
+        .ok_or_else(|| ParseError::end_of("", "a crate drawing").with_line(1))?;
+    let stack_count = parse_header(header).map_err(|error| error.with_line(drawing.len()))?;
+    if rows.is_empty() {
+        let error = Parser::new(header).error("a row of crates above the stack numbers");
+        return Err(error.with_line(1));
+    }
+
+    let mut stacks = vec![Vec::new(); stack_count];
+    for (i, line) in rows.iter().enumerate().rev() {
+        let row = parse_row(line, stack_count).map_err(|error| error.with_line(i + 1))?;
+        for (stack, name) in stacks.iter_mut().zip(row) {
+            stack.extend(name);
+        }
+    }
+
+    Ok(stacks)
+}
+
+/// The number of stacks in the header, which has to count up from 1.
+fn parse_header(header: &str) -> Result<usize, ParseError> {
+    Parser::parse_all(header, |parser| {
+        let mut count = 0;
+
+        loop {
+            let start = parser.clone();
+            match parser.unsigned::<usize>() {
+                Ok(number) if number == count + 1 => count = number,
+                _ => return Err(start.error(format!("stack number {}", count + 1))),
+            }
+
+            if parser.is_empty() {
+                return Ok(count);
+            }
+        }
+    })
+}
+
+/// One row of the drawing, where every stack has a cell of three characters,
+/// `[A]` for a crate or blank for none, and cells are separated by a space.
+/// Cells left off the end of the line are blank.
+fn parse_row(line: &str, stack_count: usize) -> Result<Vec<Option<char>>, ParseError> {
+    let mut row = Vec::with_capacity(stack_count);
+    let mut rest = line;
+
+    while !rest.trim().is_empty() {
+        if row.len() == stack_count {
+            return Err(ParseError::at(
+                line,
+                rest,
+                format!("at most {} stacks", stack_count),
+            ));
+        }
+
+        let end = rest.char_indices().nth(3).map_or(rest.len(), |(i, _)| i);
+        let (cell, after) = rest.split_at(end);
+        let mut chars = cell.chars();
+        row.push(match (chars.next(), chars.next(), chars.next()) {
+            (Some('['), Some(name), Some(']')) if !name.is_whitespace() => Some(name),
+            _ if cell.trim().is_empty() => None,
+            _ => {
+                return Err(ParseError::at(
+                    line,
+                    cell,
+                    "a crate such as '[A]' or a blank",
+                ))
+            }
+        });
+
+        rest = match after.chars().next() {
+            None | Some(' ') => after.get(1..).unwrap_or_default(),
+            Some(char) => {
+                let found = &after[..char.len_utf8()];
+                return Err(ParseError::at(line, found, "a space between stacks"));
+            }
+        };
+    }
+
+    Ok(row)
+}
+
+/// Replays the moves on the stack heights alone, so a move that would take
+/// crates from a missing or too small stack is reported while parsing instead
+/// of panicking while solving.
+fn check_moves(stacks: &[Vec<char>], moves: &[(usize, &str, Move)]) -> Result<(), ParseError> {
+    let mut heights = stacks.iter().map(|stack| stack.len()).collect::<Vec<_>>();
+
+    for (line_number, line, change) in moves {
+        let error = |expected: String| ParseError::at(line, line, expected).with_line(*line_number);
+
+        for stack in [change.from, change.to] {
+            if stack >= heights.len() {
+                return Err(error(format!("a stack between 1 and {}", heights.len())));
+            }
+        }
+
+        if heights[change.from] < change.amount {
+            return Err(error(format!(
+                "at most {} crates on stack {}",
+                heights[change.from],
+                change.from + 1
+            )));
+        }
+
+        heights[change.from] -= change.amount;
+        heights[change.to] += change.amount;
+    }
+
+    Ok(())
+}
+
+fn parse_moves(stacks: &[Vec<char>], lines: &[&str]) -> Result<Vec<Move>, ParseError> {
+    let moves = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            Move::from_str(line)
+                .map(|change| (i + 1, *line, change))
+                .map_err(|error| error.with_line(i + 1))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    check_moves(stacks, &moves)?;
+
+    Ok(moves.into_iter().map(|(_, _, change)| change).collect())
+}
+
+fn top_elements(stacks: &[Vec<char>]) -> String {
+    stacks
+        .iter()
+        .filter_map(|stack| stack.last())
+        .collect::<String>()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const PADDED_LINES: bool = true;
+
+    type Input = (Vec<Vec<char>>, Vec<Move>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut stacks = None;
+        let mut moves = None;
+
+        parse_groups(input.as_bytes(), |group| {
+            match (&stacks, &moves) {
+                (None, _) => stacks = Some(initialize_stacks(group)?),
+                (Some(stacks), None) => moves = Some(parse_moves(stacks, group)?),
+                (Some(_), Some(_)) => {
+                    return Err(ParseError::at(group[0], group[0], "end of input").with_line(1))
+                }
+            }
+            Ok(())
+        })?;
+
+        let stacks =
+            stacks.ok_or_else(|| ParseError::end_of("", "a crate drawing").with_line(1))?;
+        Ok((stacks, moves.unwrap_or_default()))
+    }
+
+    fn part1((stacks, moves): &Self::Input) -> Self::Part1 {
+        let mut stacks = stacks.clone();
+
+        for change in moves {
+            for _ in 0..change.amount {
+                let value = stacks.get_mut(change.from).unwrap().pop().unwrap();
+                stacks.get_mut(change.to).unwrap().push(value);
+            }
+        }
+
+        top_elements(&stacks)
+    }
+
+    fn part2((stacks, moves): &Self::Input) -> Self::Part2 {
+        let mut stacks = stacks.clone();
+
+        for change in moves {
+            let from = stacks.get_mut(change.from).unwrap();
+            let elements = from.drain(from.len() - change.amount..).collect::<Vec<_>>();
+            stacks.get_mut(change.to).unwrap().extend(elements);
+        }
+
+        top_elements(&stacks)
+    }
+
+    /// Nine stacks of up to eight crates, followed by `n` moves that never
+    /// take more crates than a stack holds.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let mut heights = (0..9)
+            .map(|_| rng.range(1..=8) as usize)
+            .collect::<Vec<_>>();
+        let mut input = String::new();
+
+        for row in (0..*heights.iter().max().unwrap()).rev() {
+            let crates = heights
+                .iter()
+                .map(|&height| match height > row {
+                    true => format!("[{}]", (b'A' + rng.below(26) as u8) as char),
+                    false => "   ".to_string(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(input, "{}", crates.join(" ")).unwrap();
+        }
+
+        let numbers = (1..=heights.len())
+            .map(|stack| format!(" {} ", stack))
+            .collect::<Vec<_>>();
+        writeln!(input, "{}\n", numbers.join(" ")).unwrap();
+
+        for _ in 0..size.n {
+            let from = loop {
+                let stack = rng.below(heights.len());
+                if heights[stack] > 0 {
+                    break stack;
+                }
+            };
+            let to = (from + 1 + rng.below(heights.len() - 1)) % heights.len();
+            let amount = rng.range(1..=heights[from] as i64) as usize;
+
+            heights[from] -= amount;
+            heights[to] += amount;
+            writeln!(input, "move {} from {} to {}", amount, from + 1, to + 1).unwrap();
+        }
+
+        Some(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day05>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part1(&input), "CMZ");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part2(&input), "MCD");
+    }
+
+    #[test]
+    fn rejects_malformed_drawings() {
+        let error = |input: &str| Day05::parse(input).err().unwrap();
+
+        assert_eq!(
+            error("x\n"),
+            ParseError::new(1, "stack number 1", Some("x")).with_line(1)
+        );
+        assert_eq!(
+            error(" 1   3 \n"),
+            ParseError::new(6, "stack number 2", Some("3")).with_line(1)
+        );
+        assert_eq!(
+            error(" 1 \n\n"),
+            ParseError::new(2, "a row of crates above the stack numbers", Some("1")).with_line(1)
+        );
+        assert_eq!(
+            error("éééééé\n 1 \n"),
+            ParseError::new(1, "a crate such as '[A]' or a blank", Some("ééé")).with_line(1)
+        );
+        assert_eq!(
+            error("[A]-[B]\n 1   2 \n"),
+            ParseError::new(4, "a space between stacks", Some("-")).with_line(1)
+        );
+        assert_eq!(
+            error("[A] [B]\n 1 \n"),
+            ParseError::new(5, "at most 1 stacks", Some("[B]")).with_line(1)
+        );
+    }
+}
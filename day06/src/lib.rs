@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use common::{
+    bytes,
+    generate::{Rng, Size},
+    ParseError, Registry, Solution,
+};
+
+const PACKET_LENGTH: usize = 4;
+const MESSAGE_LENGTH: usize = 14;
+
+/// Where the first `length` characters that are all different end, if
+/// there are any.
+fn find_start(input: &[u8], length: usize) -> Option<usize> {
+    let mut end = length;
+
+    while end <= input.len() {
+        let slice = &input[end - length..end];
+        let set: HashSet<u8> = HashSet::from_iter(slice.iter().copied());
+        if set.len() == length {
+            return Some(end);
+        }
+
+        end += 1;
+    }
+
+    None
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_bytes(input.as_bytes())
+    }
+
+    /// Rejects a datastream without a start-of-message marker, which also
+    /// means it has no start-of-packet marker.
+    fn parse_bytes(input: &[u8]) -> Result<Self::Input, ParseError> {
+        let line = bytes::lines(input).next().unwrap_or_default();
+        if find_start(line, MESSAGE_LENGTH).is_none() {
+            let column = String::from_utf8_lossy(line).chars().count() + 1;
+            let expected = format!("{} different characters in a row", MESSAGE_LENGTH);
+            return Err(ParseError::new(column, expected, None).with_line(1));
+        }
+
+        Ok(line.to_vec())
+    }
+
+    fn part1(line: &Self::Input) -> Self::Part1 {
+        find_start(line, PACKET_LENGTH).expect("parsing checked for a marker")
+    }
+
+    fn part2(line: &Self::Input) -> Self::Part2 {
+        find_start(line, MESSAGE_LENGTH).expect("parsing checked for a marker")
+    }
+
+    /// A datastream of `n` characters, at least 14, made of only three
+    /// letters except for one run of fourteen different ones, so both
+    /// markers exist.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let length = size.n.max(14);
+        let mut stream = (0..length).map(|_| *rng.choose(b"abc")).collect::<Vec<_>>();
+
+        let mut letters = (b'a'..=b'z').collect::<Vec<_>>();
+        rng.shuffle(&mut letters);
+        let start = rng.below(length - 13);
+        stream[start..start + 14].copy_from_slice(&letters[..14]);
+
+        stream.push(b'\n');
+        Some(String::from_utf8(stream).unwrap())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day06>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part1(&input), 7);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part2(&input), 19);
+    }
+
+    #[test]
+    fn other_examples() {
+        let examples = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+
+        for (example, packet, message) in examples {
+            let line = Day06::parse(example).unwrap();
+            assert_eq!(Day06::part1(&line), packet, "{}", example);
+            assert_eq!(Day06::part2(&line), message, "{}", example);
+        }
+    }
+
+    #[test]
+    fn rejects_streams_without_a_marker() {
+        for stream in ["", "aaaa", "abcdefghijklm\n"] {
+            let error = Day06::parse(stream).err().unwrap();
+            assert_eq!(error.expected, "14 different characters in a row");
+        }
+        assert_eq!(Day06::parse("aaaa").err().unwrap().column, 5);
+    }
+
+    /// The end of the first `length` characters that are all different,
+    /// found by comparing every pair of them.
+    fn naive_find_start(input: &[u8], length: usize) -> Option<usize> {
+        (length..=input.len()).find(|&end| {
+            let window = &input[end - length..end];
+            (0..length).all(|i| (i + 1..length).all(|j| window[i] != window[j]))
+        })
+    }
+
+    /// Checks that `input` is rejected exactly when it has no message
+    /// marker, and that both markers are found where the naive search
+    /// finds them otherwise.
+    fn assert_same_start(input: &[u8]) {
+        match Day06::parse_bytes(input) {
+            Ok(line) => {
+                let found = (Day06::part1(&line), Day06::part2(&line));
+                let expected = (naive_find_start(input, 4), naive_find_start(input, 14));
+                assert_eq!((Some(found.0), Some(found.1)), expected, "{:?}", input);
+            }
+            Err(_) => assert_eq!(naive_find_start(input, 14), None, "{:?}", input),
+        }
+    }
+
+    #[test]
+    fn matches_naive_search_on_generated_inputs() {
+        for seed in 0..200 {
+            let mut rng = Rng::new(seed);
+            let n = rng.below(100) + 1;
+            let input = Day06::generate(&mut rng, Size { n, m: n }).unwrap();
+            assert!(Day06::parse(&input).is_ok());
+            assert_same_start(input.trim_end().as_bytes());
+
+            // Streams over fewer letters, which often have no marker at all.
+            let letters = &b"abcdefghijklmnopqrstuvwxyz"[..rng.below(16) + 2];
+            let stream = (0..rng.below(60))
+                .map(|_| *rng.choose(letters))
+                .collect::<Vec<_>>();
+            assert_same_start(&stream);
+        }
+    }
+}
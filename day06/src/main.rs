@@ -1,33 +1,11 @@
-use std::{collections::HashSet, io::BufRead};
-
-use common::read_input;
-
-fn find_start(input: &String, length: usize) -> usize {
-    let mut end = length;
-
-    while end <= input.len() {
-        let slice = &input[end - length..end];
-        let set: HashSet<char> = HashSet::from_iter(slice.chars());
-        if set.len() == length {
-            break;
-        }
-
-        end += 1;
-    }
-
-    end
-}
+use common::{load_input, parse_input, Solution};
+use day06::Day06;
 
 fn main() {
-    let mut reader = read_input(env!("CARGO_CRATE_NAME"));
-    let mut line = String::new();
-    reader.read_line(&mut line).unwrap();
-
-    let packet = find_start(&line, 4);
-
-    println!("Packet starts at: {}", packet);
+    let input = load_input(env!("CARGO_CRATE_NAME"));
+    let line = parse_input::<Day06>(&input);
 
-    let message = find_start(&line, 14);
+    println!("Packet starts at: {}", Day06::part1(&line));
 
-    println!("Message starts at: {}", message);
+    println!("Message starts at: {}", Day06::part2(&line));
 }
@@ -0,0 +1,446 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use common::{
+    generate::{Rng, Size},
+    parse::Parser,
+    parse_lines, ParseError, Registry, Solution,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum NodeType {
+    Directory,
+    File,
+}
+
+struct Node {
+    name: String,
+    size: u64,
+    node_type: NodeType,
+    parent: usize,
+    children: HashMap<String, usize>,
+}
+
+impl Node {
+    fn new(name: String, size: u64, node_type: NodeType, parent: usize) -> Self {
+        Self {
+            name,
+            size,
+            node_type,
+            parent,
+            children: HashMap::new(),
+        }
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl PartialEq<String> for Node {
+    fn eq(&self, other: &String) -> bool {
+        &self.name == other
+    }
+}
+
+impl Eq for Node {}
+
+impl Hash for Node {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+const ROOT: usize = 0;
+
+/// The nodes of a filesystem, which refer to each other by their index in
+/// `nodes`, so that however deeply directories are nested, nothing has to
+/// recurse to build, walk or drop the tree. A node always comes after its
+/// parent.
+pub struct Tree {
+    nodes: Vec<Node>,
+}
+
+impl Tree {
+    fn new() -> Self {
+        Self {
+            nodes: vec![Node::new("/".into(), 0, NodeType::Directory, ROOT)],
+        }
+    }
+
+    /// The child of `dir` called `name`, added first if there is none.
+    /// Also returns whether it is new, so that listing the same directory
+    /// twice does not count its files twice.
+    fn push(&mut self, dir: usize, name: String, size: u64, node_type: NodeType) -> (usize, bool) {
+        if let Some(&child) = self.nodes[dir].children.get(&name) {
+            return (child, false);
+        }
+
+        let child = self.nodes.len();
+        self.nodes[dir].children.insert(name.clone(), child);
+        self.nodes.push(Node::new(name, size, node_type, dir));
+        (child, true)
+    }
+
+    /// The size of every node, including everything under it. Cannot
+    /// overflow, as [`parse_filesystem`] keeps the whole tree within
+    /// [`DISK_SIZE`].
+    fn sizes(&self) -> Vec<u64> {
+        let mut sizes = self.nodes.iter().map(|node| node.size).collect::<Vec<_>>();
+
+        // Going backwards, every node is complete before it is added to its
+        // parent, which comes earlier.
+        for (i, node) in self.nodes.iter().enumerate().skip(1).rev() {
+            sizes[node.parent] += sizes[i];
+        }
+
+        sizes
+    }
+
+    fn dir_sizes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.sizes()
+            .into_iter()
+            .zip(&self.nodes)
+            .filter(|(_, node)| node.node_type == NodeType::Directory)
+            .map(|(size, _)| size)
+    }
+}
+
+impl Display for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut stack = vec![(ROOT, 0)];
+
+        while let Some((i, level)) = stack.pop() {
+            let node = &self.nodes[i];
+            let line = match node.node_type {
+                NodeType::Directory => format!("{} (dir)", node.name),
+                NodeType::File => format!("{} (file size={})", node.name, node.size),
+            };
+            writeln!(f, "{}- {}", " ".repeat(level * 2), line)?;
+
+            stack.extend(node.children.values().map(|&child| (child, level + 1)));
+        }
+
+        Ok(())
+    }
+}
+
+const DISK_SIZE: u64 = 70_000_000;
+const UPDATE_SIZE: u64 = 30_000_000;
+
+/// Rebuilds the tree from a transcript, rejecting files that would not fit on
+/// the disk and entries that contradict what was listed before.
+fn parse_filesystem(input: &str) -> Result<Tree, ParseError> {
+    let mut tree = Tree::new();
+    let mut cwd = ROOT;
+    let mut used = 0u64;
+
+    let results = parse_lines(input, ParseResult::from_str)?;
+    for ((i, line), result) in input.lines().enumerate().zip(results) {
+        let error = |expected: String| Parser::new(line).error(expected).with_line(i + 1);
+
+        match result {
+            ParseResult::Command(command) => match command {
+                Command::cd(dir) => {
+                    cwd = match dir.as_str() {
+                        "/" => ROOT,
+                        ".." => tree.nodes[cwd].parent,
+                        _ => {
+                            let (node, _) = tree.push(cwd, dir, 0, NodeType::Directory);
+                            if tree.nodes[node].node_type != NodeType::Directory {
+                                let name = line.split_whitespace().last().unwrap_or(line);
+                                let expected = "a directory rather than a file";
+                                return Err(ParseError::at(line, name, expected).with_line(i + 1));
+                            }
+                            node
+                        }
+                    }
+                }
+                Command::ls => {}
+            },
+            ParseResult::File(file) => {
+                let (node_type, size) = match file.file_type {
+                    FileType::Directory => (NodeType::Directory, 0),
+                    FileType::File(size) => (NodeType::File, size),
+                };
+                let (node, new) = tree.push(cwd, file.name, size, node_type);
+
+                let listed = &tree.nodes[node];
+                if listed.node_type != node_type || listed.size != size {
+                    let before = match listed.node_type {
+                        NodeType::Directory => format!("dir {}", listed.name),
+                        NodeType::File => format!("{} {}", listed.size, listed.name),
+                    };
+                    return Err(error(format!("'{}', as listed before", before)));
+                }
+
+                if new && node_type == NodeType::File {
+                    used = used
+                        .checked_add(size)
+                        .filter(|&used| used <= DISK_SIZE)
+                        .ok_or_else(|| {
+                            error(format!(
+                                "a file size of at most {}, the space left on the disk",
+                                DISK_SIZE - used
+                            ))
+                        })?;
+                }
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+#[allow(non_camel_case_types)]
+enum Command {
+    ls,
+    cd(String),
+}
+
+struct File {
+    file_type: FileType,
+    name: String,
+}
+
+enum FileType {
+    Directory,
+    File(u64),
+}
+
+enum ParseResult {
+    Command(Command),
+    File(File),
+}
+
+impl FromStr for ParseResult {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(line, |parser| {
+            match parser.clone().word("'$', 'dir' or a file size")? {
+                "$" => {
+                    parser.keyword("$")?;
+                    let command = match parser.one_of(&[("cd", true), ("ls", false)])? {
+                        true => Command::cd(parser.word("a directory")?.to_string()),
+                        false => Command::ls,
+                    };
+                    Ok(ParseResult::Command(command))
+                }
+                word => {
+                    let file_type = if word == "dir" {
+                        parser.keyword("dir")?;
+                        FileType::Directory
+                    } else if word.starts_with(|char: char| char.is_ascii_digit()) {
+                        FileType::File(parser.unsigned()?)
+                    } else {
+                        return Err(parser.error("'$', 'dir' or a file size"));
+                    };
+
+                    Ok(ParseResult::File(File {
+                        file_type,
+                        name: parser.word("a file name")?.to_string(),
+                    }))
+                }
+            }
+        })
+    }
+}
+
+const NAMES: [&str; 6] = ["a", "b", "cfz", "dmd", "lhg", "qwm"];
+
+/// A directory of a generated tree, with files weighted by how much of the
+/// total size they take up.
+#[derive(Default)]
+struct GeneratedDir {
+    files: Vec<(String, u64)>,
+    dirs: Vec<(String, usize)>,
+}
+
+fn write_transcript(
+    dirs: &[GeneratedDir],
+    dir: usize,
+    total: u64,
+    weights: u64,
+    input: &mut String,
+) {
+    input.push_str("$ ls\n");
+    for (name, _) in &dirs[dir].dirs {
+        writeln!(input, "dir {}", name).unwrap();
+    }
+    for (name, weight) in &dirs[dir].files {
+        writeln!(input, "{} {}", (total * weight / weights).max(1), name).unwrap();
+    }
+
+    for (name, child) in &dirs[dir].dirs {
+        writeln!(input, "$ cd {}", name).unwrap();
+        write_transcript(dirs, *child, total, weights, input);
+        input.push_str("$ cd ..\n");
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Tree;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_filesystem(input)
+    }
+
+    fn part1(tree: &Self::Input) -> Self::Part1 {
+        tree.dir_sizes().filter(|&size| size <= 100_000).sum()
+    }
+
+    fn part2(tree: &Self::Input) -> Self::Part2 {
+        let used = tree.sizes()[ROOT];
+        let missing = UPDATE_SIZE.saturating_sub(DISK_SIZE - used);
+        if missing == 0 {
+            return 0;
+        }
+
+        // Deleting everything always frees enough, as `missing` is at most
+        // what is used.
+        tree.dir_sizes()
+            .filter(|&size| size >= missing)
+            .min()
+            .unwrap_or(used)
+    }
+
+    /// A transcript that explores a random tree of `n` files and
+    /// directories. The files add up to between 20 and 65 million, so part 2
+    /// sometimes has space to spare and sometimes has to free some.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let mut dirs = vec![GeneratedDir::default()];
+        let mut weights = 0;
+
+        for entry in 0..size.n {
+            let parent = rng.below(dirs.len());
+            let name = format!("{}{}", rng.choose(&NAMES), entry);
+
+            // The first entry is always a file so the disk is never empty.
+            if entry > 0 && rng.chance(0.25) {
+                let child = dirs.len();
+                dirs[parent].dirs.push((name, child));
+                dirs.push(GeneratedDir::default());
+            } else {
+                let weight = rng.range(1..=1_000) as u64;
+                dirs[parent].files.push((name, weight));
+                weights += weight;
+            }
+        }
+
+        let total = rng.range(20_000_000..=65_000_000) as u64;
+        let mut input = String::from("$ cd /\n");
+        write_transcript(&dirs, 0, total, weights, &mut input);
+
+        Some(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day07>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part1(&input), 95437);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part2(&input), 24933642);
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        let error = |line: &str| ParseResult::from_str(line).err().unwrap();
+
+        assert_eq!(
+            error("$ mkdir a"),
+            ParseError::new(3, "'cd' or 'ls'", Some("mkdir"))
+        );
+        assert_eq!(error("$ cd"), ParseError::new(5, "a directory", None));
+        assert_eq!(
+            error("file a"),
+            ParseError::new(1, "'$', 'dir' or a file size", Some("file"))
+        );
+        assert_eq!(
+            error("dir a b"),
+            ParseError::new(7, "end of line", Some("b"))
+        );
+    }
+
+    #[test]
+    fn rejects_contradictory_transcripts() {
+        let error = |input: &str| Day07::parse(input).err().unwrap();
+
+        assert_eq!(
+            error("$ ls\ndir a\n1 a\n"),
+            ParseError::new(1, "'dir a', as listed before", Some("1")).with_line(3)
+        );
+        assert_eq!(
+            error("$ ls\n5 a\n$ ls\n6 a\n"),
+            ParseError::new(1, "'5 a', as listed before", Some("6")).with_line(4)
+        );
+        assert_eq!(
+            error("$ ls\n5 a\n$ cd a\n3 b\n"),
+            ParseError::new(6, "a directory rather than a file", Some("a")).with_line(3)
+        );
+    }
+
+    #[test]
+    fn handles_deeply_nested_directories() {
+        // Deep enough to overflow the stack of a thread if the tree were
+        // built or walked recursively.
+        let input = "$ cd a\n".repeat(100_000) + "$ ls\n1 x\n";
+        let tree = Day07::parse(&input).unwrap();
+
+        assert_eq!(Day07::part1(&tree), 100_001);
+        assert_eq!(Day07::part2(&tree), 0);
+    }
+
+    #[test]
+    fn needs_no_space_when_the_disk_is_mostly_free() {
+        let input = Day07::parse("$ cd /\n$ ls\n1 a\n").unwrap();
+        assert_eq!(Day07::part1(&input), 1);
+        assert_eq!(Day07::part2(&input), 0);
+    }
+
+    #[test]
+    fn rejects_files_that_do_not_fit_on_the_disk() {
+        let error = Day07::parse("$ cd /\n$ ls\n40000000 a\n40000000 b\n").err();
+        assert_eq!(
+            error,
+            Some(
+                ParseError::new(
+                    1,
+                    "a file size of at most 30000000, the space left on the disk",
+                    Some("40000000")
+                )
+                .with_line(4)
+            )
+        );
+
+        // Listing a directory twice does not count its files twice.
+        let input = Day07::parse("$ ls\n50000000 a\n$ ls\n50000000 a\n").unwrap();
+        assert_eq!(Day07::part2(&input), 50000000);
+        assert!(Day07::parse("$ ls\n4000000000 a\n300000000 b\n").is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use common::{
+    generate::{Rng, Size},
+    geom::Direction,
+    grid::{Grid, Position},
+    ParseError, Registry, Solution,
+};
+
+pub struct Forest {
+    trees: Grid<u8>,
+}
+
+impl TryFrom<&[u8]> for Forest {
+    type Error = ParseError;
+
+    fn try_from(input: &[u8]) -> Result<Self, Self::Error> {
+        let trees = Grid::parse_bytes(input, "a tree height", |byte| {
+            byte.is_ascii_digit().then(|| byte - b'0')
+        })?;
+
+        Ok(Self { trees })
+    }
+}
+
+impl Forest {
+    fn get_visible_trees(&self) -> Vec<Position> {
+        self.trees
+            .iter()
+            .filter(|&(position, height)| {
+                Direction::ALL.into_iter().any(|direction| {
+                    !self
+                        .trees
+                        .walk(position, direction)
+                        .any(|(_, other)| other >= height)
+                })
+            })
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    fn get_highest_score(&self) -> u64 {
+        let mut highest = 0;
+
+        for (position, height) in self.trees.iter() {
+            let score = Direction::ALL
+                .into_iter()
+                .map(|direction| {
+                    let mut distance = 0u64;
+                    for (_, other) in self.trees.walk(position, direction) {
+                        distance += 1;
+                        if other >= height {
+                            break;
+                        }
+                    }
+                    distance
+                })
+                .product();
+
+            if score > highest {
+                highest = score;
+            }
+        }
+
+        highest
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Forest;
+    type Part1 = usize;
+    // Four distances that add up to less than the width and height multiply
+    // to at most (trees / 4)², which fits in a u64 for any grid in memory.
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_bytes(input.as_bytes())
+    }
+
+    fn parse_bytes(input: &[u8]) -> Result<Self::Input, ParseError> {
+        Forest::try_from(input)
+    }
+
+    fn part1(forest: &Self::Input) -> Self::Part1 {
+        forest.get_visible_trees().len()
+    }
+
+    fn part2(forest: &Self::Input) -> Self::Part2 {
+        forest.get_highest_score()
+    }
+
+    /// A grid of `n` columns and `m` rows of random heights.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::with_capacity((size.n + 1) * size.m);
+
+        for _ in 0..size.m {
+            input.extend((0..size.n).map(|_| (b'0' + rng.below(10) as u8) as char));
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day08>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part1(&input), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part2(&input), 8);
+    }
+
+    #[test]
+    fn scores_beyond_u32() {
+        let mut rows = vec!["0".repeat(601); 601];
+        rows[300].replace_range(300..301, "9");
+        let forest = Day08::parse(&(rows.join("\n") + "\n")).unwrap();
+
+        assert_eq!(Day08::part2(&forest), 300u64.pow(4));
+    }
+
+    /// Counts the visible trees and finds the highest scenic score by
+    /// indexing into the rows of the input directly.
+    fn naive_forest(input: &str) -> (usize, u64) {
+        let rows = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+        let (height, width) = (rows.len(), rows[0].len());
+        let (mut visible, mut highest) = (0, 0);
+
+        for y in 0..height {
+            for x in 0..width {
+                let tree = rows[y][x];
+                let lines_of_sight: [Vec<u8>; 4] = [
+                    (0..y).rev().map(|y| rows[y][x]).collect(),
+                    (y + 1..height).map(|y| rows[y][x]).collect(),
+                    (0..x).rev().map(|x| rows[y][x]).collect(),
+                    (x + 1..width).map(|x| rows[y][x]).collect(),
+                ];
+
+                if lines_of_sight
+                    .iter()
+                    .any(|line| line.iter().all(|&other| other < tree))
+                {
+                    visible += 1;
+                }
+
+                let score = lines_of_sight
+                    .iter()
+                    .map(|line| match line.iter().position(|&other| other >= tree) {
+                        Some(blocker) => blocker as u64 + 1,
+                        None => line.len() as u64,
+                    })
+                    .product();
+                highest = highest.max(score);
+            }
+        }
+
+        (visible, highest)
+    }
+
+    #[test]
+    fn matches_naive_forest_on_generated_inputs() {
+        for seed in 0..100 {
+            let mut rng = Rng::new(seed);
+            let size = Size {
+                n: rng.below(20) + 1,
+                m: rng.below(20) + 1,
+            };
+            let input = Day08::generate(&mut rng, size).unwrap();
+            let forest = Day08::parse(&input).unwrap();
+
+            assert_eq!(
+                (Day08::part1(&forest), Day08::part2(&forest)),
+                naive_forest(&input),
+                "{}",
+                input
+            );
+        }
+    }
+}
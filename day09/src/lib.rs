@@ -0,0 +1,142 @@
+use std::{collections::HashSet, fmt::Write, str::FromStr};
+
+use common::{
+    generate::{Rng, Size},
+    geom::{Direction, Point},
+    parse::Parser,
+    parse_lines, ParseError, Registry, Solution,
+};
+
+struct Rope {
+    head: Point,
+    knots: Vec<Point>,
+    tail_positions: HashSet<Point>,
+}
+
+impl Rope {
+    fn new(length: u8) -> Self {
+        let mut set = HashSet::new();
+        set.insert(Point::ORIGIN);
+
+        Self {
+            head: Point::ORIGIN,
+            knots: vec![Point::ORIGIN; (length - 1).into()],
+            tail_positions: set,
+        }
+    }
+
+    fn move_head(&mut self, m: &Move) {
+        for _ in 0..m.steps {
+            self.head += m.direction;
+
+            let mut parent = &self.head;
+            let length = self.knots.len();
+
+            for (i, knot) in self.knots.iter_mut().enumerate() {
+                Rope::pull_knots(parent, knot);
+                parent = knot;
+                if i == length - 1 {
+                    self.tail_positions.insert(*knot);
+                }
+            }
+        }
+    }
+
+    fn pull_knots(parent: &Point, knot: &mut Point) {
+        if parent.chebyshev(*knot) < 2 {
+            return;
+        }
+
+        *knot += (*parent - *knot).signum();
+    }
+}
+
+pub struct Move {
+    direction: Direction,
+    steps: u8,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(value, |parser| {
+            let direction = parser.parsed("a direction")?;
+            let steps = parser.unsigned()?;
+            Ok(Self { direction, steps })
+        })
+    }
+}
+
+fn count_tail_positions(moves: &[Move], length: u8) -> usize {
+    let mut rope = Rope::new(length);
+
+    for m in moves {
+        rope.move_head(m);
+    }
+
+    rope.tail_positions.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Move>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Move::from_str)
+    }
+
+    fn part1(moves: &Self::Input) -> Self::Part1 {
+        count_tail_positions(moves, 2)
+    }
+
+    fn part2(moves: &Self::Input) -> Self::Part2 {
+        count_tail_positions(moves, 10)
+    }
+
+    /// `n` moves of one to twenty steps each.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::new();
+
+        for _ in 0..size.n {
+            let direction = rng.choose(&['U', 'D', 'L', 'R']);
+            writeln!(input, "{} {}", direction, rng.range(1..=20)).unwrap();
+        }
+
+        Some(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day09>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part1(&input), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part2(&input), 1);
+    }
+
+    #[test]
+    fn part2_larger_example() {
+        let moves = Day09::parse(include_str!("../example2.txt")).unwrap();
+        assert_eq!(Day09::part2(&moves), 36);
+    }
+}
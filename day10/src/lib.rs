@@ -0,0 +1,182 @@
+use std::{fmt::Write, str::FromStr};
+
+use common::{
+    generate::{Rng, Size},
+    grid::Grid,
+    parse::Parser,
+    parse_lines, ParseError, Registry, Solution,
+};
+
+#[allow(non_camel_case_types)]
+pub enum Instruction {
+    noop,
+    addx(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(value, |parser| {
+            match parser.one_of(&[("noop", false), ("addx", true)])? {
+                false => Ok(Instruction::noop),
+                true => Ok(Instruction::addx(parser.signed()?)),
+            }
+        })
+    }
+}
+
+impl Instruction {
+    fn get_duration(&self) -> u8 {
+        match self {
+            Instruction::noop => 1,
+            Instruction::addx(_) => 2,
+        }
+    }
+}
+
+struct Output {
+    signal_strengths: Vec<i64>,
+    crt: Grid<char>,
+}
+
+fn execute(instructions: &[Instruction]) -> Output {
+    let mut instructions = instructions.iter();
+
+    let mut cycle = 0;
+    let check_cycles = [20, 60, 100, 140, 180, 220];
+    let mut cycle_duration = 0;
+    // Widened so that no 240 additions of an i32 can overflow it.
+    let mut register: i64 = 1;
+    let mut signal_strengths = Vec::with_capacity(check_cycles.len());
+    let mut add = 0;
+    let mut crt = Grid::new(40, 6, '.');
+
+    // Nothing is checked or drawn after the last pixel of the screen.
+    while cycle < 240 {
+        if cycle_duration == 0 {
+            let instruction = match instructions.next() {
+                Some(instruction) => instruction,
+                None => break,
+            };
+
+            add = match instruction {
+                Instruction::noop => 0,
+                Instruction::addx(x) => *x as i64,
+            };
+
+            cycle_duration = instruction.get_duration();
+        }
+
+        cycle += 1;
+
+        if check_cycles.contains(&cycle) {
+            signal_strengths.push(register * cycle);
+        }
+
+        let row = (cycle - 1) / 40;
+        let position = (cycle - 1) % 40;
+        if (register - position).abs() <= 1 {
+            if let Some(pixel) = crt.get_mut((row as usize, position as usize)) {
+                *pixel = '#';
+            }
+        }
+
+        cycle_duration -= 1;
+        if cycle_duration == 0 {
+            register += add;
+        }
+    }
+
+    Output {
+        signal_strengths,
+        crt,
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Part1 = i64;
+    type Part2 = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Instruction::from_str)
+    }
+
+    fn part1(instructions: &Self::Input) -> Self::Part1 {
+        execute(instructions).signal_strengths.iter().sum::<i64>()
+    }
+
+    fn part2(instructions: &Self::Input) -> Self::Part2 {
+        execute(instructions).crt
+    }
+
+    /// `n` instructions that keep the sprite somewhere on the screen.
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::new();
+        let mut register = 1;
+
+        for _ in 0..size.n {
+            if rng.chance(0.5) {
+                input.push_str("noop\n");
+            } else {
+                let target = rng.range(0..=39);
+                writeln!(input, "addx {}", target - register).unwrap();
+                register = target;
+            }
+        }
+
+        Some(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day10>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), 13140);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Day10::part2(&input).to_string(),
+            [
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......####",
+                "#######.......#######.......#######.....",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn handles_registers_beyond_i32() {
+        let input = Day10::parse(&format!("addx {}\n", i32::MAX).repeat(300)).unwrap();
+        // Each addx takes two cycles, so `(cycle - 1) / 2` have finished.
+        let expected = [20, 60, 100, 140, 180, 220]
+            .iter()
+            .map(|&cycle: &i64| cycle * (1 + (cycle - 1) / 2 * i32::MAX as i64))
+            .sum();
+        assert_eq!(Day10::part1(&input), expected);
+        // The sprite is only on screen until the first addx finishes.
+        assert_eq!(Day10::part2(&input).to_string().matches('#').count(), 2);
+    }
+}
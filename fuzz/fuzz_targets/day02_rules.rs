@@ -0,0 +1,19 @@
+#![no_main]
+
+use day02::rules::{Outcome, Rules};
+use libfuzzer_sys::fuzz_target;
+
+// Rules files are as untrusted as inputs: they must parse or be rejected, and
+// rules that parse must have every outcome against every hand.
+fuzz_target!(|rules: &[u8]| {
+    let Some(Ok(rules)) = std::str::from_utf8(rules).ok().map(str::parse::<Rules>) else {
+        return;
+    };
+
+    for opponent in rules.hands().iter().filter_map(|name| rules.hand_named(name)) {
+        for outcome in Outcome::ALL {
+            let player = rules.hand_for(opponent, outcome);
+            assert_eq!(rules.outcome(player, opponent), outcome);
+        }
+    }
+});
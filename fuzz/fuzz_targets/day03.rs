@@ -0,0 +1,13 @@
+#![no_main]
+
+use common::Solution;
+use day03::Day03;
+use libfuzzer_sys::fuzz_target;
+
+// Any input must either be rejected with an error or be solved, never panic.
+fuzz_target!(|input: &[u8]| {
+    if let Ok(input) = Day03::parse_bytes(input) {
+        Day03::part1(&input);
+        Day03::part2(&input);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use common::Solution;
+use day09::Day09;
+use libfuzzer_sys::fuzz_target;
+
+// Any input must either be rejected with an error or be solved, never panic.
+fuzz_target!(|input: &[u8]| {
+    if let Ok(input) = Day09::parse_bytes(input) {
+        Day09::part1(&input);
+        Day09::part2(&input);
+    }
+});